@@ -0,0 +1,68 @@
+use raffia::{ast::*, AstNode, Parser, Span, Spanned, Syntax, VisitMut, Walk};
+
+const HASH: &str = "a1b2c3";
+
+/// Prepend hash to every literal class name,
+/// remembering where each class selector was so the source can be patched.
+struct Scoper {
+    replacements: Vec<(Span, String)>,
+}
+
+impl<'s> VisitMut<'s> for Scoper {
+    fn visit_mut_class_selector(&mut self, class: &mut ClassSelector<'s>) {
+        if let InterpolableIdent::Literal(ident) = &mut class.name {
+            ident.name = format!("{}-{}", HASH, ident.name).into();
+            self.replacements
+                .push((class.span.clone(), format!(".{}", ident.name)));
+        }
+    }
+}
+
+fn class_names<'s>(stylesheet: &Stylesheet<'s>) -> Vec<String> {
+    let mut names = vec![];
+    stylesheet.walk(&mut |node| {
+        if let AstNode::SimpleSelector(SimpleSelector::Class(ClassSelector {
+            name: InterpolableIdent::Literal(ident),
+            ..
+        })) = node
+        {
+            names.push(ident.name.to_string());
+        }
+        true
+    });
+    names
+}
+
+fn main() {
+    let code = "
+.btn, .card > .title:hover {}
+#main .btn.active {}
+@media (min-width: 600px) {
+    .card {}
+}
+";
+    let mut parser = Parser::new(code, Syntax::Css);
+    let mut stylesheet = parser.parse::<Stylesheet>().unwrap();
+
+    let mut scoper = Scoper {
+        replacements: vec![],
+    };
+    scoper.visit_mut(&mut stylesheet);
+    let expected = class_names(&stylesheet);
+
+    let mut output = String::with_capacity(code.len());
+    let mut last = 0;
+    for (span, replacement) in &scoper.replacements {
+        output.push_str(&code[last..span.start]);
+        output.push_str(replacement);
+        last = span.end;
+    }
+    output.push_str(&code[last..]);
+    println!("{}", output);
+
+    let mut parser = Parser::new(&output, Syntax::Css);
+    let reparsed = parser.parse::<Stylesheet>().unwrap();
+    assert_eq!(class_names(&reparsed), expected);
+    assert!(expected.iter().all(|name| name.starts_with(HASH)));
+    assert_eq!(reparsed.span().end, output.len());
+}
@@ -1,12 +1,16 @@
 //! All kinds of AST nodes are here.
 
-use crate::{pos::Span, tokenizer::TokenWithSpan, util::CowStr};
-use raffia_macro::{EnumAsIs, SpanIgnoredEq, Spanned};
+use crate::{
+    pos::{Span, Spanned},
+    tokenizer::TokenWithSpan,
+    util::CowStr,
+};
+use raffia_macro::{EnumAsIs, SpanIgnoredEq, Spanned, Walk};
 #[cfg(feature = "serialize")]
 use serde::Serialize;
 use smallvec::SmallVec;
 
-#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq)]
+#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, Walk)]
 #[cfg_attr(feature = "serialize", derive(Serialize))]
 #[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
 pub struct Angle<'s> {
@@ -15,7 +19,7 @@ pub struct Angle<'s> {
     pub span: Span,
 }
 
-#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq)]
+#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, Walk)]
 #[cfg_attr(feature = "serialize", derive(Serialize))]
 #[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
 pub struct AnPlusB {
@@ -24,17 +28,21 @@ pub struct AnPlusB {
     pub span: Span,
 }
 
-#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq)]
+#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, Walk)]
 #[cfg_attr(feature = "serialize", derive(Serialize))]
 #[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
+#[ast_node]
 pub struct AtRule<'s> {
     pub name: Ident<'s>,
     pub prelude: Option<AtRulePrelude<'s>>,
+    /// Source text between at-keyword and block or `;`,
+    /// only available when `preserve_raw` option is enabled.
+    pub raw_prelude: Option<&'s str>,
     pub block: Option<SimpleBlock<'s>>,
     pub span: Span,
 }
 
-#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, EnumAsIs)]
+#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, Walk, EnumAsIs)]
 #[cfg_attr(feature = "serialize", derive(Serialize))]
 #[cfg_attr(feature = "serialize", serde(untagged))]
 pub enum AtRulePrelude<'s> {
@@ -54,13 +62,28 @@ pub enum AtRulePrelude<'s> {
     Nest(SelectorList<'s>),
     Page(PageSelectorList<'s>),
     PositionFallback(InterpolableIdent<'s>),
+    /// `@position-try` uses generic [`AtRule`] like other at-rules,
+    /// so its block is a [`SimpleBlock`] and nested rules are kept as is.
+    PositionTry(InterpolableIdent<'s>),
     Property(InterpolableIdent<'s>),
     ScrollTimeline(InterpolableIdent<'s>),
     Supports(SupportsCondition<'s>),
     Unknown(TokenSeq<'s>),
 }
 
-#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq)]
+#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, Walk)]
+#[cfg_attr(feature = "serialize", derive(Serialize))]
+#[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
+pub struct AttrFunction<'s> {
+    pub name: Ident<'s>,
+    pub attr_name: WqName<'s>,
+    /// `<type>` like `string` and `color`, or unit like `px`
+    pub type_or_unit: Option<Ident<'s>>,
+    pub fallback: Option<ComponentValues<'s>>,
+    pub span: Span,
+}
+
+#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, Walk)]
 #[cfg_attr(feature = "serialize", derive(Serialize))]
 #[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
 pub struct AttributeSelector<'s> {
@@ -71,7 +94,7 @@ pub struct AttributeSelector<'s> {
     pub span: Span,
 }
 
-#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq)]
+#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, Walk)]
 #[cfg_attr(feature = "serialize", derive(Serialize))]
 #[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
 pub struct AttributeSelectorMatcher {
@@ -79,7 +102,7 @@ pub struct AttributeSelectorMatcher {
     pub span: Span,
 }
 
-#[derive(Clone, Debug, PartialEq, SpanIgnoredEq, EnumAsIs)]
+#[derive(Clone, Debug, PartialEq, SpanIgnoredEq, Walk, EnumAsIs)]
 #[cfg_attr(feature = "serialize", derive(Serialize))]
 pub enum AttributeSelectorMatcherKind {
     /// `=`
@@ -96,7 +119,7 @@ pub enum AttributeSelectorMatcherKind {
     Substring,
 }
 
-#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq)]
+#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, Walk)]
 #[cfg_attr(feature = "serialize", derive(Serialize))]
 #[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
 pub struct AttributeSelectorModifier<'s> {
@@ -104,7 +127,7 @@ pub struct AttributeSelectorModifier<'s> {
     pub span: Span,
 }
 
-#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, EnumAsIs)]
+#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, Walk, EnumAsIs)]
 #[cfg_attr(feature = "serialize", derive(Serialize))]
 #[cfg_attr(feature = "serialize", serde(untagged))]
 pub enum AttributeSelectorValue<'s> {
@@ -112,7 +135,7 @@ pub enum AttributeSelectorValue<'s> {
     Str(InterpolableStr<'s>),
 }
 
-#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq)]
+#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, Walk)]
 #[cfg_attr(feature = "serialize", derive(Serialize))]
 #[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
 pub struct BracketBlock<'s> {
@@ -120,7 +143,7 @@ pub struct BracketBlock<'s> {
     pub span: Span,
 }
 
-#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq)]
+#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, Walk)]
 #[cfg_attr(feature = "serialize", derive(Serialize))]
 #[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
 pub struct Calc<'s> {
@@ -130,7 +153,7 @@ pub struct Calc<'s> {
     pub span: Span,
 }
 
-#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq)]
+#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, Walk)]
 #[cfg_attr(feature = "serialize", derive(Serialize))]
 #[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
 pub struct CalcOperator {
@@ -138,7 +161,7 @@ pub struct CalcOperator {
     pub span: Span,
 }
 
-#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, EnumAsIs)]
+#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, Walk, EnumAsIs)]
 #[cfg_attr(feature = "serialize", derive(Serialize))]
 pub enum CalcOperatorKind {
     Plus,
@@ -147,7 +170,7 @@ pub enum CalcOperatorKind {
     Division,
 }
 
-#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq)]
+#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, Walk)]
 #[cfg_attr(feature = "serialize", derive(Serialize))]
 #[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
 pub struct ClassSelector<'s> {
@@ -155,7 +178,18 @@ pub struct ClassSelector<'s> {
     pub span: Span,
 }
 
-#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, EnumAsIs)]
+/// Named color, `currentColor` or `transparent` in value position,
+/// except values of properties like `font-family` which are names.
+/// Original casing is kept in `name`.
+#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, Walk)]
+#[cfg_attr(feature = "serialize", derive(Serialize))]
+#[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
+pub struct ColorKeyword<'s> {
+    pub name: Ident<'s>,
+    pub span: Span,
+}
+
+#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, Walk, EnumAsIs)]
 #[cfg_attr(feature = "serialize", derive(Serialize))]
 #[cfg_attr(feature = "serialize", serde(untagged))]
 pub enum ColorProfilePrelude<'s> {
@@ -163,7 +197,7 @@ pub enum ColorProfilePrelude<'s> {
     DeviceCmyk(Ident<'s>),
 }
 
-#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq)]
+#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, Walk)]
 #[cfg_attr(feature = "serialize", derive(Serialize))]
 #[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
 pub struct Combinator {
@@ -171,7 +205,7 @@ pub struct Combinator {
     pub span: Span,
 }
 
-#[derive(Clone, Debug, PartialEq, SpanIgnoredEq, EnumAsIs)]
+#[derive(Clone, Debug, PartialEq, SpanIgnoredEq, Walk, EnumAsIs)]
 #[cfg_attr(feature = "serialize", derive(Serialize))]
 pub enum CombinatorKind {
     /// ` `
@@ -186,15 +220,16 @@ pub enum CombinatorKind {
     Column,
 }
 
-#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq)]
+#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, Walk)]
 #[cfg_attr(feature = "serialize", derive(Serialize))]
 #[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
+#[ast_node]
 pub struct ComplexSelector<'s> {
     pub children: SmallVec<[ComplexSelectorChild<'s>; 3]>,
     pub span: Span,
 }
 
-#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, EnumAsIs)]
+#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, Walk, EnumAsIs)]
 #[cfg_attr(feature = "serialize", derive(Serialize))]
 #[cfg_attr(feature = "serialize", serde(untagged))]
 pub enum ComplexSelectorChild<'s> {
@@ -202,26 +237,38 @@ pub enum ComplexSelectorChild<'s> {
     Combinator(Combinator),
 }
 
-#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, EnumAsIs)]
+#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, Walk, EnumAsIs)]
 #[cfg_attr(feature = "serialize", derive(Serialize))]
 #[cfg_attr(feature = "serialize", serde(untagged))]
+#[ast_node]
 pub enum ComponentValue<'s> {
+    AttrFunction(Box<AttrFunction<'s>>),
     BracketBlock(BracketBlock<'s>),
     Calc(Calc<'s>),
+    ColorKeyword(ColorKeyword<'s>),
+    CounterFunction(Box<CounterFunction<'s>>),
+    CountersFunction(Box<CountersFunction<'s>>),
     Delimiter(Delimiter),
     Dimension(Dimension<'s>),
+    FontFaceSrc(FontFaceSrc<'s>),
+    FormatFunction(FormatFunction<'s>),
     Function(Function<'s>),
     HexColor(HexColor<'s>),
     IdSelector(IdSelector<'s>),
     InterpolableIdent(InterpolableIdent<'s>),
     InterpolableStr(InterpolableStr<'s>),
     LayerName(LayerName<'s>),
+    LessBinaryOperation(LessBinaryOperation<'s>),
+    LessFunction(LessFunction<'s>),
+    LessParenthesizedOperation(LessParenthesizedOperation<'s>),
+    LessUnaryOperation(LessUnaryOperation<'s>),
     LessVariable(LessVariable<'s>),
     LessVariableVariable(LessVariableVariable<'s>),
     Number(Number<'s>),
     Percentage(Percentage<'s>),
     Ratio(Ratio<'s>),
     SassBinaryExpression(SassBinaryExpression<'s>),
+    SassList(SassList<'s>),
     SassMap(SassMap<'s>),
     SassNamespacedExpression(SassNamespacedExpression<'s>),
     SassNestingDeclaration(SassNestingDeclaration<'s>),
@@ -229,12 +276,13 @@ pub enum ComponentValue<'s> {
     SassParentSelector(NestingSelector),
     SassUnaryExpression(SassUnaryExpression<'s>),
     SassVariable(SassVariable<'s>),
+    ThemeFunction(ThemeFunction<'s>),
     TokenWithSpan(TokenWithSpan<'s>),
     UnicodeRange(UnicodeRange<'s>),
     Url(Url<'s>),
 }
 
-#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq)]
+#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, Walk)]
 #[cfg_attr(feature = "serialize", derive(Serialize))]
 #[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
 pub struct ComponentValues<'s> {
@@ -242,15 +290,16 @@ pub struct ComponentValues<'s> {
     pub span: Span,
 }
 
-#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq)]
+#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, Walk)]
 #[cfg_attr(feature = "serialize", derive(Serialize))]
 #[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
+#[ast_node]
 pub struct CompoundSelector<'s> {
     pub children: Vec<SimpleSelector<'s>>,
     pub span: Span,
 }
 
-#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq)]
+#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, Walk)]
 #[cfg_attr(feature = "serialize", derive(Serialize))]
 #[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
 pub struct CompoundSelectorList<'s> {
@@ -258,7 +307,7 @@ pub struct CompoundSelectorList<'s> {
     pub span: Span,
 }
 
-#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq)]
+#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, Walk)]
 #[cfg_attr(feature = "serialize", derive(Serialize))]
 #[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
 pub struct ContainerCondition<'s> {
@@ -266,7 +315,7 @@ pub struct ContainerCondition<'s> {
     pub span: Span,
 }
 
-#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, EnumAsIs)]
+#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, Walk, EnumAsIs)]
 #[cfg_attr(feature = "serialize", derive(Serialize))]
 #[cfg_attr(feature = "serialize", serde(untagged))]
 pub enum ContainerConditionKind<'s> {
@@ -276,7 +325,7 @@ pub enum ContainerConditionKind<'s> {
     Not(ContainerConditionNot<'s>),
 }
 
-#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq)]
+#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, Walk)]
 #[cfg_attr(feature = "serialize", derive(Serialize))]
 #[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
 pub struct ContainerConditionAnd<'s> {
@@ -285,7 +334,7 @@ pub struct ContainerConditionAnd<'s> {
     pub span: Span,
 }
 
-#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq)]
+#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, Walk)]
 #[cfg_attr(feature = "serialize", derive(Serialize))]
 #[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
 pub struct ContainerConditionNot<'s> {
@@ -294,7 +343,7 @@ pub struct ContainerConditionNot<'s> {
     pub span: Span,
 }
 
-#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq)]
+#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, Walk)]
 #[cfg_attr(feature = "serialize", derive(Serialize))]
 #[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
 pub struct ContainerConditionOr<'s> {
@@ -303,7 +352,7 @@ pub struct ContainerConditionOr<'s> {
     pub span: Span,
 }
 
-#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq)]
+#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, Walk)]
 #[cfg_attr(feature = "serialize", derive(Serialize))]
 #[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
 pub struct ContainerPrelude<'s> {
@@ -312,7 +361,36 @@ pub struct ContainerPrelude<'s> {
     pub span: Span,
 }
 
-#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq)]
+#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, Walk)]
+#[cfg_attr(feature = "serialize", derive(Serialize))]
+#[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
+pub struct CounterFunction<'s> {
+    pub name: Ident<'s>,
+    pub counter_name: InterpolableIdent<'s>,
+    pub style: Option<CounterStyle<'s>>,
+    pub span: Span,
+}
+
+#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, Walk, EnumAsIs)]
+#[cfg_attr(feature = "serialize", derive(Serialize))]
+#[cfg_attr(feature = "serialize", serde(untagged))]
+pub enum CounterStyle<'s> {
+    Name(InterpolableIdent<'s>),
+    Symbols(Function<'s>),
+}
+
+#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, Walk)]
+#[cfg_attr(feature = "serialize", derive(Serialize))]
+#[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
+pub struct CountersFunction<'s> {
+    pub name: Ident<'s>,
+    pub counter_name: InterpolableIdent<'s>,
+    pub separator: InterpolableStr<'s>,
+    pub style: Option<CounterStyle<'s>>,
+    pub span: Span,
+}
+
+#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, Walk)]
 #[cfg_attr(feature = "serialize", derive(Serialize))]
 #[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
 pub struct CustomMedia<'s> {
@@ -321,7 +399,7 @@ pub struct CustomMedia<'s> {
     pub span: Span,
 }
 
-#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, EnumAsIs)]
+#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, Walk, EnumAsIs)]
 #[cfg_attr(feature = "serialize", derive(Serialize))]
 #[cfg_attr(feature = "serialize", serde(untagged))]
 pub enum CustomMediaValue<'s> {
@@ -330,9 +408,10 @@ pub enum CustomMediaValue<'s> {
     False(Ident<'s>),
 }
 
-#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq)]
+#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, Walk)]
 #[cfg_attr(feature = "serialize", derive(Serialize))]
 #[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
+#[ast_node]
 pub struct Declaration<'s> {
     pub name: InterpolableIdent<'s>,
     pub value: Vec<ComponentValue<'s>>,
@@ -341,7 +420,34 @@ pub struct Declaration<'s> {
     pub span: Span,
 }
 
-#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq)]
+impl<'s> Declaration<'s> {
+    /// Get source text of declaration value from the given source code,
+    /// which must be the code this declaration is parsed from.
+    ///
+    /// Whitespaces around value and `!important` annotation are excluded.
+    /// It returns `None` if value is empty.
+    ///
+    /// ```rust
+    /// use raffia::{ast::Declaration, Parser, Syntax};
+    ///
+    /// let source = "margin:  0 auto  !important";
+    /// let mut parser = Parser::new(source, Syntax::Css);
+    /// let declaration = parser.parse::<Declaration>().unwrap();
+    /// assert_eq!(declaration.raw_value(source), Some("0 auto"));
+    ///
+    /// let source = "--empty: ";
+    /// let mut parser = Parser::new(source, Syntax::Css);
+    /// let declaration = parser.parse::<Declaration>().unwrap();
+    /// assert_eq!(declaration.raw_value(source), None);
+    /// ```
+    pub fn raw_value(&self, source: &'s str) -> Option<&'s str> {
+        let start = self.value.first()?.span().start;
+        let end = self.value.last()?.span().end;
+        source.get(start..end)
+    }
+}
+
+#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, Walk)]
 #[cfg_attr(feature = "serialize", derive(Serialize))]
 #[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
 pub struct Delimiter {
@@ -349,7 +455,7 @@ pub struct Delimiter {
     pub span: Span,
 }
 
-#[derive(Clone, Debug, PartialEq, SpanIgnoredEq, EnumAsIs)]
+#[derive(Clone, Debug, PartialEq, SpanIgnoredEq, Walk, EnumAsIs)]
 #[cfg_attr(feature = "serialize", derive(Serialize))]
 pub enum DelimiterKind {
     Comma,
@@ -357,7 +463,7 @@ pub enum DelimiterKind {
     Semicolon,
 }
 
-#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, EnumAsIs)]
+#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, Walk, EnumAsIs)]
 #[cfg_attr(feature = "serialize", derive(Serialize))]
 #[cfg_attr(feature = "serialize", serde(untagged))]
 pub enum Dimension<'s> {
@@ -370,7 +476,7 @@ pub enum Dimension<'s> {
     Unknown(UnknownDimension<'s>),
 }
 
-#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq)]
+#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, Walk)]
 #[cfg_attr(feature = "serialize", derive(Serialize))]
 #[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
 pub struct DocumentPrelude<'s> {
@@ -378,7 +484,7 @@ pub struct DocumentPrelude<'s> {
     pub span: Span,
 }
 
-#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, EnumAsIs)]
+#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, Walk, EnumAsIs)]
 #[cfg_attr(feature = "serialize", derive(Serialize))]
 #[cfg_attr(feature = "serialize", serde(untagged))]
 pub enum DocumentPreludeMatcher<'s> {
@@ -386,7 +492,7 @@ pub enum DocumentPreludeMatcher<'s> {
     Function(Function<'s>),
 }
 
-#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq)]
+#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, Walk)]
 #[cfg_attr(feature = "serialize", derive(Serialize))]
 #[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
 pub struct Duration<'s> {
@@ -395,7 +501,7 @@ pub struct Duration<'s> {
     pub span: Span,
 }
 
-#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq)]
+#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, Walk)]
 #[cfg_attr(feature = "serialize", derive(Serialize))]
 #[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
 pub struct Flex<'s> {
@@ -404,7 +510,34 @@ pub struct Flex<'s> {
     pub span: Span,
 }
 
-#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, EnumAsIs)]
+/// Fallback chain of `src` descriptor in `@font-face`.
+#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, Walk)]
+#[cfg_attr(feature = "serialize", derive(Serialize))]
+#[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
+pub struct FontFaceSrc<'s> {
+    pub entries: Vec<FontFaceSrcEntry<'s>>,
+    pub span: Span,
+}
+
+#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, Walk)]
+#[cfg_attr(feature = "serialize", derive(Serialize))]
+#[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
+pub struct FontFaceSrcEntry<'s> {
+    pub source: FontFaceSrcSource<'s>,
+    pub format: Option<FormatFunction<'s>>,
+    pub tech: Option<Function<'s>>,
+    pub span: Span,
+}
+
+#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, Walk, EnumAsIs)]
+#[cfg_attr(feature = "serialize", derive(Serialize))]
+#[cfg_attr(feature = "serialize", serde(untagged))]
+pub enum FontFaceSrcSource<'s> {
+    Local(Function<'s>),
+    Url(Url<'s>),
+}
+
+#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, Walk, EnumAsIs)]
 #[cfg_attr(feature = "serialize", derive(Serialize))]
 #[cfg_attr(feature = "serialize", serde(untagged))]
 pub enum FontFamilyName<'s> {
@@ -412,7 +545,24 @@ pub enum FontFamilyName<'s> {
     Unquoted(UnquotedFontFamilyName<'s>),
 }
 
-#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq)]
+#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, Walk, EnumAsIs)]
+#[cfg_attr(feature = "serialize", derive(Serialize))]
+#[cfg_attr(feature = "serialize", serde(untagged))]
+pub enum FontFormat<'s> {
+    Str(InterpolableStr<'s>),
+    Unquoted(UnquotedFontFormat<'s>),
+}
+
+#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, Walk)]
+#[cfg_attr(feature = "serialize", derive(Serialize))]
+#[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
+pub struct FormatFunction<'s> {
+    pub name: Ident<'s>,
+    pub formats: Vec<FontFormat<'s>>,
+    pub span: Span,
+}
+
+#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, Walk)]
 #[cfg_attr(feature = "serialize", derive(Serialize))]
 #[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
 pub struct Function<'s> {
@@ -421,7 +571,7 @@ pub struct Function<'s> {
     pub span: Span,
 }
 
-#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq)]
+#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, Walk)]
 #[cfg_attr(feature = "serialize", derive(Serialize))]
 #[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
 pub struct Frequency<'s> {
@@ -430,7 +580,7 @@ pub struct Frequency<'s> {
     pub span: Span,
 }
 
-#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq)]
+#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, Walk)]
 #[cfg_attr(feature = "serialize", derive(Serialize))]
 #[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
 pub struct HexColor<'s> {
@@ -439,7 +589,7 @@ pub struct HexColor<'s> {
     pub span: Span,
 }
 
-#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq)]
+#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, Walk)]
 #[cfg_attr(feature = "serialize", derive(Serialize))]
 #[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
 pub struct Ident<'s> {
@@ -448,10 +598,11 @@ pub struct Ident<'s> {
     pub span: Span,
 }
 
-#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq)]
+#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, Walk)]
 #[cfg_attr(feature = "serialize", derive(Serialize))]
 #[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
 pub struct ImportPrelude<'s> {
+    pub less_options: Option<LessImportOptions<'s>>,
     pub href: ImportPreludeHref<'s>,
     pub layer: Option<ImportPreludeLayer<'s>>,
     pub supports: Option<ImportPreludeSupports<'s>>,
@@ -459,7 +610,7 @@ pub struct ImportPrelude<'s> {
     pub span: Span,
 }
 
-#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, EnumAsIs)]
+#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, Walk, EnumAsIs)]
 #[cfg_attr(feature = "serialize", derive(Serialize))]
 #[cfg_attr(feature = "serialize", serde(untagged))]
 pub enum ImportPreludeHref<'s> {
@@ -467,7 +618,7 @@ pub enum ImportPreludeHref<'s> {
     Url(Url<'s>),
 }
 
-#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, EnumAsIs)]
+#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, Walk, EnumAsIs)]
 #[cfg_attr(feature = "serialize", derive(Serialize))]
 #[cfg_attr(feature = "serialize", serde(untagged))]
 pub enum ImportPreludeLayer<'s> {
@@ -475,7 +626,7 @@ pub enum ImportPreludeLayer<'s> {
     WithName(Function<'s>),
 }
 
-#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, EnumAsIs)]
+#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, Walk, EnumAsIs)]
 #[cfg_attr(feature = "serialize", derive(Serialize))]
 #[cfg_attr(feature = "serialize", serde(untagged))]
 pub enum ImportPreludeSupports<'s> {
@@ -483,7 +634,7 @@ pub enum ImportPreludeSupports<'s> {
     Declaration(Declaration<'s>),
 }
 
-#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, EnumAsIs)]
+#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, Walk, EnumAsIs)]
 #[cfg_attr(feature = "serialize", derive(Serialize))]
 #[cfg_attr(feature = "serialize", serde(untagged))]
 pub enum InterpolableIdent<'s> {
@@ -492,7 +643,7 @@ pub enum InterpolableIdent<'s> {
     LessInterpolated(LessInterpolatedIdent<'s>),
 }
 
-#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq)]
+#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, Walk)]
 #[cfg_attr(feature = "serialize", derive(Serialize))]
 #[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
 pub struct InterpolableIdentStaticPart<'s> {
@@ -501,7 +652,7 @@ pub struct InterpolableIdentStaticPart<'s> {
     pub span: Span,
 }
 
-#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, EnumAsIs)]
+#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, Walk, EnumAsIs)]
 #[cfg_attr(feature = "serialize", derive(Serialize))]
 #[cfg_attr(feature = "serialize", serde(untagged))]
 pub enum InterpolableStr<'s> {
@@ -510,7 +661,7 @@ pub enum InterpolableStr<'s> {
     LessInterpolated(LessInterpolatedStr<'s>),
 }
 
-#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq)]
+#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, Walk)]
 #[cfg_attr(feature = "serialize", derive(Serialize))]
 #[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
 pub struct InterpolableStrStaticPart<'s> {
@@ -519,7 +670,7 @@ pub struct InterpolableStrStaticPart<'s> {
     pub span: Span,
 }
 
-#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq)]
+#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, Walk)]
 #[cfg_attr(feature = "serialize", derive(Serialize))]
 #[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
 pub struct InterpolableUrlStaticPart<'s> {
@@ -528,7 +679,7 @@ pub struct InterpolableUrlStaticPart<'s> {
     pub span: Span,
 }
 
-#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq)]
+#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, Walk)]
 #[cfg_attr(feature = "serialize", derive(Serialize))]
 #[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
 pub struct IdSelector<'s> {
@@ -536,7 +687,7 @@ pub struct IdSelector<'s> {
     pub span: Span,
 }
 
-#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq)]
+#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, Walk)]
 #[cfg_attr(feature = "serialize", derive(Serialize))]
 #[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
 pub struct ImportantAnnotation<'s> {
@@ -544,16 +695,17 @@ pub struct ImportantAnnotation<'s> {
     pub span: Span,
 }
 
-#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq)]
+#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, Walk)]
 #[cfg_attr(feature = "serialize", derive(Serialize))]
 #[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
+#[ast_node]
 pub struct KeyframeBlock<'s> {
     pub selectors: SmallVec<[KeyframeSelector<'s>; 3]>,
     pub block: SimpleBlock<'s>,
     pub span: Span,
 }
 
-#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, EnumAsIs)]
+#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, Walk, EnumAsIs)]
 #[cfg_attr(feature = "serialize", derive(Serialize))]
 #[cfg_attr(feature = "serialize", serde(untagged))]
 pub enum KeyframeSelector<'s> {
@@ -561,7 +713,7 @@ pub enum KeyframeSelector<'s> {
     Percentage(Percentage<'s>),
 }
 
-#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, EnumAsIs)]
+#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, Walk, EnumAsIs)]
 #[cfg_attr(feature = "serialize", derive(Serialize))]
 #[cfg_attr(feature = "serialize", serde(untagged))]
 pub enum KeyframesName<'s> {
@@ -569,7 +721,7 @@ pub enum KeyframesName<'s> {
     Str(InterpolableStr<'s>),
 }
 
-#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, EnumAsIs)]
+#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, Walk, EnumAsIs)]
 #[cfg_attr(feature = "serialize", derive(Serialize))]
 #[cfg_attr(feature = "serialize", serde(untagged))]
 pub enum LanguageRange<'s> {
@@ -577,7 +729,7 @@ pub enum LanguageRange<'s> {
     Ident(InterpolableIdent<'s>),
 }
 
-#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq)]
+#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, Walk)]
 #[cfg_attr(feature = "serialize", derive(Serialize))]
 #[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
 pub struct LanguageRangeList<'s> {
@@ -585,7 +737,7 @@ pub struct LanguageRangeList<'s> {
     pub span: Span,
 }
 
-#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq)]
+#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, Walk)]
 #[cfg_attr(feature = "serialize", derive(Serialize))]
 #[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
 pub struct LayerName<'s> {
@@ -593,7 +745,108 @@ pub struct LayerName<'s> {
     pub span: Span,
 }
 
-#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq)]
+#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, Walk)]
+#[cfg_attr(feature = "serialize", derive(Serialize))]
+#[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
+pub struct LessBinaryCondition<'s> {
+    pub left: Box<LessCondition<'s>>,
+    pub op: LessBinaryConditionOperator,
+    pub right: Box<LessCondition<'s>>,
+    pub span: Span,
+}
+
+#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, Walk)]
+#[cfg_attr(feature = "serialize", derive(Serialize))]
+#[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
+pub struct LessBinaryConditionOperator {
+    pub kind: LessBinaryConditionOperatorKind,
+    pub span: Span,
+}
+
+#[derive(Clone, Debug, PartialEq, SpanIgnoredEq, Walk, EnumAsIs)]
+#[cfg_attr(feature = "serialize", derive(Serialize))]
+pub enum LessBinaryConditionOperatorKind {
+    GreaterThan,
+    GreaterThanOrEqual,
+    LessThan,
+    LessThanOrEqual,
+    Equal,
+    And,
+    Or,
+}
+
+#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, Walk)]
+#[cfg_attr(feature = "serialize", derive(Serialize))]
+#[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
+pub struct LessBinaryOperation<'s> {
+    pub left: Box<ComponentValue<'s>>,
+    pub op: LessOperationOperator,
+    pub right: Box<ComponentValue<'s>>,
+    pub span: Span,
+}
+
+#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, Walk, EnumAsIs)]
+#[cfg_attr(feature = "serialize", derive(Serialize))]
+#[cfg_attr(feature = "serialize", serde(untagged))]
+pub enum LessCondition<'s> {
+    Binary(LessBinaryCondition<'s>),
+    Function(LessGuardFunction<'s>),
+    Negated(LessNegatedCondition<'s>),
+    Parenthesized(LessParenthesizedCondition<'s>),
+    Value(ComponentValue<'s>),
+}
+
+/// Less built-in color function, such as `lighten(@base, 10%)`.
+#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, Walk)]
+#[cfg_attr(feature = "serialize", derive(Serialize))]
+#[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
+pub struct LessFunction<'s> {
+    pub name: Ident<'s>,
+    pub args: Vec<ComponentValue<'s>>,
+    pub span: Span,
+}
+
+/// Guard starts with `when`.
+/// Comma-separated conditions mean that the guard matches if any of them matches.
+#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, Walk)]
+#[cfg_attr(feature = "serialize", derive(Serialize))]
+#[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
+pub struct LessGuard<'s> {
+    pub conditions: Vec<LessCondition<'s>>,
+    pub span: Span,
+}
+
+/// Type-checking function or `default()` in guard, such as `iscolor(@c)`.
+#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, Walk)]
+#[cfg_attr(feature = "serialize", derive(Serialize))]
+#[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
+pub struct LessGuardFunction<'s> {
+    pub name: Ident<'s>,
+    pub arg: Option<Box<ComponentValue<'s>>>,
+    pub span: Span,
+}
+
+/// Rule with guard, such as `& when (@mode = dark) {}`.
+#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, Walk)]
+#[cfg_attr(feature = "serialize", derive(Serialize))]
+#[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
+pub struct LessGuardedRule<'s> {
+    pub selector: SelectorList<'s>,
+    pub guard: LessGuard<'s>,
+    pub block: SimpleBlock<'s>,
+    pub span: Span,
+}
+
+/// Options of Less `@import`, such as `(reference)` or `(optional, css)`.
+#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, Walk)]
+#[cfg_attr(feature = "serialize", derive(Serialize))]
+#[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
+pub struct LessImportOptions<'s> {
+    pub names: Vec<Ident<'s>>,
+    pub span: Span,
+}
+
+#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, Walk)]
 #[cfg_attr(feature = "serialize", derive(Serialize))]
 #[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
 pub struct LessInterpolatedIdent<'s> {
@@ -601,7 +854,7 @@ pub struct LessInterpolatedIdent<'s> {
     pub span: Span,
 }
 
-#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, EnumAsIs)]
+#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, Walk, EnumAsIs)]
 #[cfg_attr(feature = "serialize", derive(Serialize))]
 #[cfg_attr(feature = "serialize", serde(untagged))]
 pub enum LessInterpolatedIdentElement<'s> {
@@ -609,7 +862,7 @@ pub enum LessInterpolatedIdentElement<'s> {
     Static(InterpolableIdentStaticPart<'s>),
 }
 
-#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq)]
+#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, Walk)]
 #[cfg_attr(feature = "serialize", derive(Serialize))]
 #[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
 pub struct LessInterpolatedStr<'s> {
@@ -617,7 +870,7 @@ pub struct LessInterpolatedStr<'s> {
     pub span: Span,
 }
 
-#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, EnumAsIs)]
+#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, Walk, EnumAsIs)]
 #[cfg_attr(feature = "serialize", derive(Serialize))]
 #[cfg_attr(feature = "serialize", serde(untagged))]
 pub enum LessInterpolatedStrElement<'s> {
@@ -625,7 +878,76 @@ pub enum LessInterpolatedStrElement<'s> {
     Static(InterpolableStrStaticPart<'s>),
 }
 
-#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq)]
+/// Less mixin call, such as `.mixin();` or `#ns > .mixin(1, 2);`.
+#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, Walk)]
+#[cfg_attr(feature = "serialize", derive(Serialize))]
+#[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
+pub struct LessMixinCall<'s> {
+    pub callee: Vec<LessMixinCalleeSegment<'s>>,
+    pub args: Option<Vec<ComponentValue<'s>>>,
+    pub important: Option<ImportantAnnotation<'s>>,
+    pub span: Span,
+}
+
+#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, Walk)]
+#[cfg_attr(feature = "serialize", derive(Serialize))]
+#[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
+pub struct LessMixinCalleeSegment<'s> {
+    pub combinator: Option<Combinator>,
+    pub name: LessMixinName<'s>,
+    pub span: Span,
+}
+
+#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, Walk, EnumAsIs)]
+#[cfg_attr(feature = "serialize", derive(Serialize))]
+#[cfg_attr(feature = "serialize", serde(untagged))]
+pub enum LessMixinName<'s> {
+    ClassSelector(ClassSelector<'s>),
+    IdSelector(IdSelector<'s>),
+}
+
+#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, Walk)]
+#[cfg_attr(feature = "serialize", derive(Serialize))]
+#[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
+pub struct LessNegatedCondition<'s> {
+    pub condition: Box<LessCondition<'s>>,
+    pub span: Span,
+}
+
+#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, Walk)]
+#[cfg_attr(feature = "serialize", derive(Serialize))]
+#[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
+pub struct LessOperationOperator {
+    pub kind: LessOperationOperatorKind,
+    pub span: Span,
+}
+
+#[derive(Clone, Debug, PartialEq, SpanIgnoredEq, Walk, EnumAsIs)]
+#[cfg_attr(feature = "serialize", derive(Serialize))]
+pub enum LessOperationOperatorKind {
+    Multiply,
+    Division,
+    Plus,
+    Minus,
+}
+
+#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, Walk)]
+#[cfg_attr(feature = "serialize", derive(Serialize))]
+#[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
+pub struct LessParenthesizedCondition<'s> {
+    pub condition: Box<LessCondition<'s>>,
+    pub span: Span,
+}
+
+#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, Walk)]
+#[cfg_attr(feature = "serialize", derive(Serialize))]
+#[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
+pub struct LessParenthesizedOperation<'s> {
+    pub operation: Box<ComponentValue<'s>>,
+    pub span: Span,
+}
+
+#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, Walk)]
 #[cfg_attr(feature = "serialize", derive(Serialize))]
 #[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
 pub struct LessPropertyMerge {
@@ -633,14 +955,38 @@ pub struct LessPropertyMerge {
     pub span: Span,
 }
 
-#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, EnumAsIs)]
+#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, Walk, EnumAsIs)]
 #[cfg_attr(feature = "serialize", derive(Serialize))]
 pub enum LessPropertyMergeKind {
     Comma,
     Space,
 }
 
-#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq)]
+#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, Walk)]
+#[cfg_attr(feature = "serialize", derive(Serialize))]
+#[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
+pub struct LessUnaryOperation<'s> {
+    pub op: LessUnaryOperator,
+    pub expr: Box<ComponentValue<'s>>,
+    pub span: Span,
+}
+
+#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, Walk)]
+#[cfg_attr(feature = "serialize", derive(Serialize))]
+#[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
+pub struct LessUnaryOperator {
+    pub kind: LessUnaryOperatorKind,
+    pub span: Span,
+}
+
+#[derive(Clone, Debug, PartialEq, SpanIgnoredEq, Walk, EnumAsIs)]
+#[cfg_attr(feature = "serialize", derive(Serialize))]
+pub enum LessUnaryOperatorKind {
+    Plus,
+    Minus,
+}
+
+#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, Walk)]
 #[cfg_attr(feature = "serialize", derive(Serialize))]
 #[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
 pub struct LessVariable<'s> {
@@ -648,7 +994,7 @@ pub struct LessVariable<'s> {
     pub span: Span,
 }
 
-#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq)]
+#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, Walk)]
 #[cfg_attr(feature = "serialize", derive(Serialize))]
 #[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
 pub struct LessVariableDeclaration<'s> {
@@ -657,7 +1003,7 @@ pub struct LessVariableDeclaration<'s> {
     pub span: Span,
 }
 
-#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq)]
+#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, Walk)]
 #[cfg_attr(feature = "serialize", derive(Serialize))]
 #[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
 pub struct LessVariableInterpolation<'s> {
@@ -665,7 +1011,7 @@ pub struct LessVariableInterpolation<'s> {
     pub span: Span,
 }
 
-#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq)]
+#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, Walk)]
 #[cfg_attr(feature = "serialize", derive(Serialize))]
 #[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
 pub struct LessVariableVariable<'s> {
@@ -673,7 +1019,7 @@ pub struct LessVariableVariable<'s> {
     pub span: Span,
 }
 
-#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq)]
+#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, Walk)]
 #[cfg_attr(feature = "serialize", derive(Serialize))]
 #[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
 pub struct Length<'s> {
@@ -682,7 +1028,7 @@ pub struct Length<'s> {
     pub span: Span,
 }
 
-#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq)]
+#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, Walk)]
 #[cfg_attr(feature = "serialize", derive(Serialize))]
 #[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
 pub struct MediaAnd<'s> {
@@ -691,7 +1037,7 @@ pub struct MediaAnd<'s> {
     pub span: Span,
 }
 
-#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq)]
+#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, Walk)]
 #[cfg_attr(feature = "serialize", derive(Serialize))]
 #[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
 pub struct MediaCondition<'s> {
@@ -699,7 +1045,7 @@ pub struct MediaCondition<'s> {
     pub span: Span,
 }
 
-#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, EnumAsIs)]
+#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, Walk, EnumAsIs)]
 #[cfg_attr(feature = "serialize", derive(Serialize))]
 #[cfg_attr(feature = "serialize", serde(untagged))]
 pub enum MediaConditionKind<'s> {
@@ -709,7 +1055,7 @@ pub enum MediaConditionKind<'s> {
     Not(MediaNot<'s>),
 }
 
-#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, EnumAsIs)]
+#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, Walk, EnumAsIs)]
 #[cfg_attr(feature = "serialize", derive(Serialize))]
 #[cfg_attr(feature = "serialize", serde(untagged))]
 pub enum MediaFeature<'s> {
@@ -719,7 +1065,7 @@ pub enum MediaFeature<'s> {
     RangeInterval(MediaFeatureRangeInterval<'s>),
 }
 
-#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq)]
+#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, Walk)]
 #[cfg_attr(feature = "serialize", derive(Serialize))]
 #[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
 pub struct MediaFeatureComparison {
@@ -727,7 +1073,7 @@ pub struct MediaFeatureComparison {
     pub span: Span,
 }
 
-#[derive(Clone, Debug, PartialEq, SpanIgnoredEq, EnumAsIs)]
+#[derive(Clone, Debug, PartialEq, SpanIgnoredEq, Walk, EnumAsIs)]
 #[cfg_attr(feature = "serialize", derive(Serialize))]
 pub enum MediaFeatureComparisonKind {
     LessThan,
@@ -737,14 +1083,14 @@ pub enum MediaFeatureComparisonKind {
     Equal,
 }
 
-#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, EnumAsIs)]
+#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, Walk, EnumAsIs)]
 #[cfg_attr(feature = "serialize", derive(Serialize))]
 #[cfg_attr(feature = "serialize", serde(untagged))]
 pub enum MediaFeatureName<'s> {
     Ident(InterpolableIdent<'s>),
 }
 
-#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq)]
+#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, Walk)]
 #[cfg_attr(feature = "serialize", derive(Serialize))]
 #[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
 pub struct MediaFeatureBoolean<'s> {
@@ -752,7 +1098,7 @@ pub struct MediaFeatureBoolean<'s> {
     pub span: Span,
 }
 
-#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq)]
+#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, Walk)]
 #[cfg_attr(feature = "serialize", derive(Serialize))]
 #[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
 pub struct MediaFeaturePlain<'s> {
@@ -761,7 +1107,7 @@ pub struct MediaFeaturePlain<'s> {
     pub span: Span,
 }
 
-#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq)]
+#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, Walk)]
 #[cfg_attr(feature = "serialize", derive(Serialize))]
 #[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
 pub struct MediaFeatureRange<'s> {
@@ -771,7 +1117,7 @@ pub struct MediaFeatureRange<'s> {
     pub span: Span,
 }
 
-#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq)]
+#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, Walk)]
 #[cfg_attr(feature = "serialize", derive(Serialize))]
 #[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
 pub struct MediaFeatureRangeInterval<'s> {
@@ -783,15 +1129,18 @@ pub struct MediaFeatureRangeInterval<'s> {
     pub span: Span,
 }
 
-#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, EnumAsIs)]
+#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, Walk, EnumAsIs)]
 #[cfg_attr(feature = "serialize", derive(Serialize))]
 #[cfg_attr(feature = "serialize", serde(untagged))]
 pub enum MediaInParens<'s> {
     MediaCondition(MediaCondition<'s>),
     MediaFeature(Box<MediaFeature<'s>>),
+    /// Sass interpolation in place of parenthesized condition,
+    /// such as `#{$cond}` in `screen and #{$cond}`.
+    SassInterpolated(SassInterpolatedIdent<'s>),
 }
 
-#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq)]
+#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, Walk)]
 #[cfg_attr(feature = "serialize", derive(Serialize))]
 #[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
 pub struct MediaNot<'s> {
@@ -800,7 +1149,7 @@ pub struct MediaNot<'s> {
     pub span: Span,
 }
 
-#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq)]
+#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, Walk)]
 #[cfg_attr(feature = "serialize", derive(Serialize))]
 #[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
 pub struct MediaOr<'s> {
@@ -809,15 +1158,17 @@ pub struct MediaOr<'s> {
     pub span: Span,
 }
 
-#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, EnumAsIs)]
+#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, Walk, EnumAsIs)]
 #[cfg_attr(feature = "serialize", derive(Serialize))]
 #[cfg_attr(feature = "serialize", serde(untagged))]
 pub enum MediaQuery<'s> {
     ConditionOnly(MediaCondition<'s>),
     WithType(MediaQueryWithType<'s>),
+    /// Invalid media query which is treated as `not all`.
+    Invalid(TokenSeq<'s>),
 }
 
-#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq)]
+#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, Walk)]
 #[cfg_attr(feature = "serialize", derive(Serialize))]
 #[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
 pub struct MediaQueryList<'s> {
@@ -825,7 +1176,7 @@ pub struct MediaQueryList<'s> {
     pub span: Span,
 }
 
-#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq)]
+#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, Walk)]
 #[cfg_attr(feature = "serialize", derive(Serialize))]
 #[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
 pub struct MediaQueryWithType<'s> {
@@ -835,7 +1186,7 @@ pub struct MediaQueryWithType<'s> {
     pub span: Span,
 }
 
-#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq)]
+#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, Walk)]
 #[cfg_attr(feature = "serialize", derive(Serialize))]
 #[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
 pub struct NamespacePrelude<'s> {
@@ -844,7 +1195,7 @@ pub struct NamespacePrelude<'s> {
     pub span: Span,
 }
 
-#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, EnumAsIs)]
+#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, Walk, EnumAsIs)]
 #[cfg_attr(feature = "serialize", derive(Serialize))]
 #[cfg_attr(feature = "serialize", serde(untagged))]
 pub enum NamespacePreludeUri<'s> {
@@ -852,14 +1203,14 @@ pub enum NamespacePreludeUri<'s> {
     Url(Url<'s>),
 }
 
-#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq)]
+#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, Walk)]
 #[cfg_attr(feature = "serialize", derive(Serialize))]
 #[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
 pub struct NestingSelector {
     pub span: Span,
 }
 
-#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq)]
+#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, Walk)]
 #[cfg_attr(feature = "serialize", derive(Serialize))]
 #[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
 pub struct NsPrefix<'s> {
@@ -867,7 +1218,7 @@ pub struct NsPrefix<'s> {
     pub span: Span,
 }
 
-#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, EnumAsIs)]
+#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, Walk, EnumAsIs)]
 #[cfg_attr(feature = "serialize", derive(Serialize))]
 #[cfg_attr(feature = "serialize", serde(untagged))]
 pub enum NsPrefixKind<'s> {
@@ -875,14 +1226,14 @@ pub enum NsPrefixKind<'s> {
     Universal(NsPrefixUniversal),
 }
 
-#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq)]
+#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, Walk)]
 #[cfg_attr(feature = "serialize", derive(Serialize))]
 #[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
 pub struct NsPrefixUniversal {
     pub span: Span,
 }
 
-#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, EnumAsIs)]
+#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, Walk, EnumAsIs)]
 #[cfg_attr(feature = "serialize", derive(Serialize))]
 #[cfg_attr(feature = "serialize", serde(untagged))]
 pub enum Nth<'s> {
@@ -890,9 +1241,23 @@ pub enum Nth<'s> {
     Even(Ident<'s>),
     Integer(Number<'s>),
     AnPlusB(AnPlusB),
+    /// Sass interpolation which can't be resolved statically,
+    /// such as `#{$i}` or `2n + #{$offset}`.
+    Interpolated(ComponentValues<'s>),
 }
 
-#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq)]
+/// Argument of `:nth-child()` and `:nth-last-child()`
+/// which is followed by `of` and a selector list, such as `2n+1 of .foo, .bar`.
+#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, Walk)]
+#[cfg_attr(feature = "serialize", derive(Serialize))]
+#[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
+pub struct NthOf<'s> {
+    pub nth: Nth<'s>,
+    pub selector_list: Box<SelectorList<'s>>,
+    pub span: Span,
+}
+
+#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, Walk)]
 #[cfg_attr(feature = "serialize", derive(Serialize))]
 #[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
 pub struct Number<'s> {
@@ -901,7 +1266,7 @@ pub struct Number<'s> {
     pub span: Span,
 }
 
-#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq)]
+#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, Walk)]
 #[cfg_attr(feature = "serialize", derive(Serialize))]
 #[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
 pub struct PageSelector<'s> {
@@ -910,7 +1275,7 @@ pub struct PageSelector<'s> {
     pub span: Span,
 }
 
-#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq)]
+#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, Walk)]
 #[cfg_attr(feature = "serialize", derive(Serialize))]
 #[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
 pub struct PageSelectorList<'s> {
@@ -918,7 +1283,7 @@ pub struct PageSelectorList<'s> {
     pub span: Span,
 }
 
-#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq)]
+#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, Walk)]
 #[cfg_attr(feature = "serialize", derive(Serialize))]
 #[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
 pub struct Percentage<'s> {
@@ -926,7 +1291,7 @@ pub struct Percentage<'s> {
     pub span: Span,
 }
 
-#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq)]
+#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, Walk)]
 #[cfg_attr(feature = "serialize", derive(Serialize))]
 #[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
 pub struct PseudoClassSelector<'s> {
@@ -935,7 +1300,7 @@ pub struct PseudoClassSelector<'s> {
     pub span: Span,
 }
 
-#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, EnumAsIs)]
+#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, Walk, EnumAsIs)]
 #[cfg_attr(feature = "serialize", derive(Serialize))]
 #[cfg_attr(feature = "serialize", serde(untagged))]
 pub enum PseudoClassSelectorArg<'s> {
@@ -944,13 +1309,14 @@ pub enum PseudoClassSelectorArg<'s> {
     Ident(InterpolableIdent<'s>),
     LanguageRangeList(LanguageRangeList<'s>),
     Nth(Nth<'s>),
+    NthOf(NthOf<'s>),
     Number(Number<'s>),
     RelativeSelectorList(RelativeSelectorList<'s>),
     SelectorList(Box<SelectorList<'s>>),
     TokenSeq(TokenSeq<'s>),
 }
 
-#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq)]
+#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, Walk)]
 #[cfg_attr(feature = "serialize", derive(Serialize))]
 #[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
 pub struct PseudoElementSelector<'s> {
@@ -959,7 +1325,7 @@ pub struct PseudoElementSelector<'s> {
     pub span: Span,
 }
 
-#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, EnumAsIs)]
+#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, Walk, EnumAsIs)]
 #[cfg_attr(feature = "serialize", derive(Serialize))]
 #[cfg_attr(feature = "serialize", serde(untagged))]
 pub enum PseudoElementSelectorArg<'s> {
@@ -968,7 +1334,7 @@ pub enum PseudoElementSelectorArg<'s> {
     TokenSeq(TokenSeq<'s>),
 }
 
-#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq)]
+#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, Walk)]
 #[cfg_attr(feature = "serialize", derive(Serialize))]
 #[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
 pub struct PseudoPage<'s> {
@@ -976,16 +1342,17 @@ pub struct PseudoPage<'s> {
     pub span: Span,
 }
 
-#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq)]
+#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, Walk)]
 #[cfg_attr(feature = "serialize", derive(Serialize))]
 #[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
+#[ast_node]
 pub struct QualifiedRule<'s> {
     pub selector: SelectorList<'s>,
     pub block: SimpleBlock<'s>,
     pub span: Span,
 }
 
-#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, EnumAsIs)]
+#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, Walk, EnumAsIs)]
 #[cfg_attr(feature = "serialize", derive(Serialize))]
 #[cfg_attr(feature = "serialize", serde(untagged))]
 pub enum QueryInParens<'s> {
@@ -994,7 +1361,7 @@ pub enum QueryInParens<'s> {
     StyleQuery(StyleQuery<'s>),
 }
 
-#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq)]
+#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, Walk)]
 #[cfg_attr(feature = "serialize", derive(Serialize))]
 #[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
 pub struct Ratio<'s> {
@@ -1003,7 +1370,7 @@ pub struct Ratio<'s> {
     pub span: Span,
 }
 
-#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq)]
+#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, Walk)]
 #[cfg_attr(feature = "serialize", derive(Serialize))]
 #[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
 pub struct RelativeSelector<'s> {
@@ -1012,7 +1379,7 @@ pub struct RelativeSelector<'s> {
     pub span: Span,
 }
 
-#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq)]
+#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, Walk)]
 #[cfg_attr(feature = "serialize", derive(Serialize))]
 #[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
 pub struct RelativeSelectorList<'s> {
@@ -1020,7 +1387,7 @@ pub struct RelativeSelectorList<'s> {
     pub span: Span,
 }
 
-#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq)]
+#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, Walk)]
 #[cfg_attr(feature = "serialize", derive(Serialize))]
 #[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
 pub struct Resolution<'s> {
@@ -1029,7 +1396,7 @@ pub struct Resolution<'s> {
     pub span: Span,
 }
 
-#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq)]
+#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, Walk)]
 #[cfg_attr(feature = "serialize", derive(Serialize))]
 #[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
 pub struct SassArbitraryArgument<'s> {
@@ -1037,7 +1404,7 @@ pub struct SassArbitraryArgument<'s> {
     pub span: Span,
 }
 
-#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq)]
+#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, Walk)]
 #[cfg_attr(feature = "serialize", derive(Serialize))]
 #[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
 pub struct SassArbitraryParameter<'s> {
@@ -1045,7 +1412,7 @@ pub struct SassArbitraryParameter<'s> {
     pub span: Span,
 }
 
-#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq)]
+#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, Walk)]
 #[cfg_attr(feature = "serialize", derive(Serialize))]
 #[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
 pub struct SassBinaryExpression<'s> {
@@ -1055,7 +1422,7 @@ pub struct SassBinaryExpression<'s> {
     pub span: Span,
 }
 
-#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq)]
+#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, Walk)]
 #[cfg_attr(feature = "serialize", derive(Serialize))]
 #[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
 pub struct SassBinaryOperator {
@@ -1063,10 +1430,11 @@ pub struct SassBinaryOperator {
     pub span: Span,
 }
 
-#[derive(Clone, Debug, PartialEq, SpanIgnoredEq, EnumAsIs)]
+#[derive(Clone, Debug, PartialEq, SpanIgnoredEq, Walk, EnumAsIs)]
 #[cfg_attr(feature = "serialize", derive(Serialize))]
 pub enum SassBinaryOperatorKind {
     Multiply,
+    Division,
     Modulo,
     Plus,
     Minus,
@@ -1080,7 +1448,7 @@ pub enum SassBinaryOperatorKind {
     Or,
 }
 
-#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq)]
+#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, Walk)]
 #[cfg_attr(feature = "serialize", derive(Serialize))]
 #[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
 pub struct SassConditionalClause<'s> {
@@ -1089,7 +1457,7 @@ pub struct SassConditionalClause<'s> {
     pub span: Span,
 }
 
-#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq)]
+#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, Walk)]
 #[cfg_attr(feature = "serialize", derive(Serialize))]
 #[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
 pub struct SassContentAtRule<'s> {
@@ -1097,7 +1465,7 @@ pub struct SassContentAtRule<'s> {
     pub span: Span,
 }
 
-#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq)]
+#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, Walk)]
 #[cfg_attr(feature = "serialize", derive(Serialize))]
 #[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
 pub struct SassDebugAtRule<'s> {
@@ -1105,7 +1473,7 @@ pub struct SassDebugAtRule<'s> {
     pub span: Span,
 }
 
-#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq)]
+#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, Walk)]
 #[cfg_attr(feature = "serialize", derive(Serialize))]
 #[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
 pub struct SassEachAtRule<'s> {
@@ -1115,7 +1483,7 @@ pub struct SassEachAtRule<'s> {
     pub span: Span,
 }
 
-#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq)]
+#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, Walk)]
 #[cfg_attr(feature = "serialize", derive(Serialize))]
 #[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
 pub struct SassErrorAtRule<'s> {
@@ -1123,7 +1491,7 @@ pub struct SassErrorAtRule<'s> {
     pub span: Span,
 }
 
-#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq)]
+#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, Walk)]
 #[cfg_attr(feature = "serialize", derive(Serialize))]
 #[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
 pub struct SassExtendAtRule<'s> {
@@ -1132,7 +1500,7 @@ pub struct SassExtendAtRule<'s> {
     pub span: Span,
 }
 
-#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq)]
+#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, Walk)]
 #[cfg_attr(feature = "serialize", derive(Serialize))]
 #[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
 pub struct SassFlag<'s> {
@@ -1140,7 +1508,7 @@ pub struct SassFlag<'s> {
     pub span: Span,
 }
 
-#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq)]
+#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, Walk)]
 #[cfg_attr(feature = "serialize", derive(Serialize))]
 #[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
 pub struct SassForAtRule<'s> {
@@ -1152,7 +1520,7 @@ pub struct SassForAtRule<'s> {
     pub span: Span,
 }
 
-#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq)]
+#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, Walk)]
 #[cfg_attr(feature = "serialize", derive(Serialize))]
 #[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
 pub struct SassForwardAtRule<'s> {
@@ -1163,7 +1531,7 @@ pub struct SassForwardAtRule<'s> {
     pub span: Span,
 }
 
-#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, EnumAsIs)]
+#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, Walk, EnumAsIs)]
 #[cfg_attr(feature = "serialize", derive(Serialize))]
 #[cfg_attr(feature = "serialize", serde(untagged))]
 pub enum SassForwardMember<'s> {
@@ -1171,7 +1539,7 @@ pub enum SassForwardMember<'s> {
     Variable(SassVariable<'s>),
 }
 
-#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq)]
+#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, Walk)]
 #[cfg_attr(feature = "serialize", derive(Serialize))]
 #[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
 pub struct SassForwardVisibility<'s> {
@@ -1180,7 +1548,7 @@ pub struct SassForwardVisibility<'s> {
     pub span: Span,
 }
 
-#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, EnumAsIs)]
+#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, Walk, EnumAsIs)]
 #[cfg_attr(feature = "serialize", derive(Serialize))]
 #[cfg_attr(feature = "serialize", serde(untagged))]
 pub enum SassForwardVisibilityKind {
@@ -1188,7 +1556,7 @@ pub enum SassForwardVisibilityKind {
     Show,
 }
 
-#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq)]
+#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, Walk)]
 #[cfg_attr(feature = "serialize", derive(Serialize))]
 #[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
 pub struct SassFunctionAtRule<'s> {
@@ -1199,7 +1567,7 @@ pub struct SassFunctionAtRule<'s> {
     pub span: Span,
 }
 
-#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq)]
+#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, Walk)]
 #[cfg_attr(feature = "serialize", derive(Serialize))]
 #[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
 pub struct SassIfAtRule<'s> {
@@ -1209,7 +1577,7 @@ pub struct SassIfAtRule<'s> {
     pub span: Span,
 }
 
-#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq)]
+#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, Walk)]
 #[cfg_attr(feature = "serialize", derive(Serialize))]
 #[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
 pub struct SassIncludeAtRule<'s> {
@@ -1222,7 +1590,7 @@ pub struct SassIncludeAtRule<'s> {
     pub span: Span,
 }
 
-#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq)]
+#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, Walk)]
 #[cfg_attr(feature = "serialize", derive(Serialize))]
 #[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
 pub struct SassIncludeAtRuleArgument<'s> {
@@ -1231,7 +1599,7 @@ pub struct SassIncludeAtRuleArgument<'s> {
     pub span: Span,
 }
 
-#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq)]
+#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, Walk)]
 #[cfg_attr(feature = "serialize", derive(Serialize))]
 #[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
 pub struct SassInterpolatedIdent<'s> {
@@ -1239,7 +1607,7 @@ pub struct SassInterpolatedIdent<'s> {
     pub span: Span,
 }
 
-#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, EnumAsIs)]
+#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, Walk, EnumAsIs)]
 #[cfg_attr(feature = "serialize", derive(Serialize))]
 #[cfg_attr(feature = "serialize", serde(untagged))]
 pub enum SassInterpolatedIdentElement<'s> {
@@ -1247,7 +1615,7 @@ pub enum SassInterpolatedIdentElement<'s> {
     Static(InterpolableIdentStaticPart<'s>),
 }
 
-#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq)]
+#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, Walk)]
 #[cfg_attr(feature = "serialize", derive(Serialize))]
 #[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
 pub struct SassInterpolatedStr<'s> {
@@ -1255,7 +1623,7 @@ pub struct SassInterpolatedStr<'s> {
     pub span: Span,
 }
 
-#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, EnumAsIs)]
+#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, Walk, EnumAsIs)]
 #[cfg_attr(feature = "serialize", derive(Serialize))]
 #[cfg_attr(feature = "serialize", serde(untagged))]
 pub enum SassInterpolatedStrElement<'s> {
@@ -1263,7 +1631,7 @@ pub enum SassInterpolatedStrElement<'s> {
     Static(InterpolableStrStaticPart<'s>),
 }
 
-#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq)]
+#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, Walk)]
 #[cfg_attr(feature = "serialize", derive(Serialize))]
 #[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
 pub struct SassInterpolatedUrl<'s> {
@@ -1271,7 +1639,7 @@ pub struct SassInterpolatedUrl<'s> {
     pub span: Span,
 }
 
-#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, EnumAsIs)]
+#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, Walk, EnumAsIs)]
 #[cfg_attr(feature = "serialize", derive(Serialize))]
 #[cfg_attr(feature = "serialize", serde(untagged))]
 pub enum SassInterpolatedUrlElement<'s> {
@@ -1279,7 +1647,16 @@ pub enum SassInterpolatedUrlElement<'s> {
     Static(InterpolableUrlStaticPart<'s>),
 }
 
-#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq)]
+/// Parenthesized list, such as `(1, 2)` or `(1 2)`.
+#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, Walk)]
+#[cfg_attr(feature = "serialize", derive(Serialize))]
+#[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
+pub struct SassList<'s> {
+    pub elements: Vec<ComponentValue<'s>>,
+    pub span: Span,
+}
+
+#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, Walk)]
 #[cfg_attr(feature = "serialize", derive(Serialize))]
 #[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
 pub struct SassMap<'s> {
@@ -1287,7 +1664,7 @@ pub struct SassMap<'s> {
     pub span: Span,
 }
 
-#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq)]
+#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, Walk)]
 #[cfg_attr(feature = "serialize", derive(Serialize))]
 #[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
 pub struct SassMapItem<'s> {
@@ -1296,7 +1673,7 @@ pub struct SassMapItem<'s> {
     pub span: Span,
 }
 
-#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq)]
+#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, Walk)]
 #[cfg_attr(feature = "serialize", derive(Serialize))]
 #[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
 pub struct SassMixinAtRule<'s> {
@@ -1307,7 +1684,7 @@ pub struct SassMixinAtRule<'s> {
     pub span: Span,
 }
 
-#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq)]
+#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, Walk)]
 #[cfg_attr(feature = "serialize", derive(Serialize))]
 #[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
 pub struct SassModuleConfigItem<'s> {
@@ -1318,7 +1695,7 @@ pub struct SassModuleConfigItem<'s> {
     pub span: Span,
 }
 
-#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq)]
+#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, Walk)]
 #[cfg_attr(feature = "serialize", derive(Serialize))]
 #[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
 pub struct SassNamespacedExpression<'s> {
@@ -1327,7 +1704,7 @@ pub struct SassNamespacedExpression<'s> {
     pub span: Span,
 }
 
-#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq)]
+#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, Walk)]
 #[cfg_attr(feature = "serialize", derive(Serialize))]
 #[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
 pub struct SassNestingDeclaration<'s> {
@@ -1335,7 +1712,7 @@ pub struct SassNestingDeclaration<'s> {
     pub span: Span,
 }
 
-#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq)]
+#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, Walk)]
 #[cfg_attr(feature = "serialize", derive(Serialize))]
 #[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
 pub struct SassParameter<'s> {
@@ -1344,7 +1721,7 @@ pub struct SassParameter<'s> {
     pub span: Span,
 }
 
-#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq)]
+#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, Walk)]
 #[cfg_attr(feature = "serialize", derive(Serialize))]
 #[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
 pub struct SassParenthesizedExpression<'s> {
@@ -1352,7 +1729,7 @@ pub struct SassParenthesizedExpression<'s> {
     pub span: Span,
 }
 
-#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq)]
+#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, Walk)]
 #[cfg_attr(feature = "serialize", derive(Serialize))]
 #[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
 pub struct SassPlaceholderSelector<'s> {
@@ -1360,7 +1737,7 @@ pub struct SassPlaceholderSelector<'s> {
     pub span: Span,
 }
 
-#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq)]
+#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, Walk)]
 #[cfg_attr(feature = "serialize", derive(Serialize))]
 #[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
 pub struct SassReturnAtRule<'s> {
@@ -1368,7 +1745,7 @@ pub struct SassReturnAtRule<'s> {
     pub span: Span,
 }
 
-#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq)]
+#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, Walk)]
 #[cfg_attr(feature = "serialize", derive(Serialize))]
 #[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
 pub struct SassUnaryExpression<'s> {
@@ -1377,7 +1754,7 @@ pub struct SassUnaryExpression<'s> {
     pub span: Span,
 }
 
-#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq)]
+#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, Walk)]
 #[cfg_attr(feature = "serialize", derive(Serialize))]
 #[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
 pub struct SassUnaryOperator {
@@ -1385,7 +1762,7 @@ pub struct SassUnaryOperator {
     pub span: Span,
 }
 
-#[derive(Clone, Debug, PartialEq, SpanIgnoredEq, EnumAsIs)]
+#[derive(Clone, Debug, PartialEq, SpanIgnoredEq, Walk, EnumAsIs)]
 #[cfg_attr(feature = "serialize", derive(Serialize))]
 pub enum SassUnaryOperatorKind {
     Plus,
@@ -1393,14 +1770,14 @@ pub enum SassUnaryOperatorKind {
     Not,
 }
 
-#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq)]
+#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, Walk)]
 #[cfg_attr(feature = "serialize", derive(Serialize))]
 #[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
 pub struct SassUnnamedNamespace {
     pub span: Span,
 }
 
-#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq)]
+#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, Walk)]
 #[cfg_attr(feature = "serialize", derive(Serialize))]
 #[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
 pub struct SassUseAtRule<'s> {
@@ -1410,7 +1787,7 @@ pub struct SassUseAtRule<'s> {
     pub span: Span,
 }
 
-#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, EnumAsIs)]
+#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, Walk, EnumAsIs)]
 #[cfg_attr(feature = "serialize", derive(Serialize))]
 #[cfg_attr(feature = "serialize", serde(untagged))]
 pub enum SassUseNamespace<'s> {
@@ -1418,7 +1795,7 @@ pub enum SassUseNamespace<'s> {
     Unnamed(SassUnnamedNamespace),
 }
 
-#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq)]
+#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, Walk)]
 #[cfg_attr(feature = "serialize", derive(Serialize))]
 #[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
 pub struct SassVariable<'s> {
@@ -1426,7 +1803,7 @@ pub struct SassVariable<'s> {
     pub span: Span,
 }
 
-#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq)]
+#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, Walk)]
 #[cfg_attr(feature = "serialize", derive(Serialize))]
 #[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
 pub struct SassVariableDeclaration<'s> {
@@ -1437,7 +1814,7 @@ pub struct SassVariableDeclaration<'s> {
     pub span: Span,
 }
 
-#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq)]
+#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, Walk)]
 #[cfg_attr(feature = "serialize", derive(Serialize))]
 #[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
 pub struct SassWarnAtRule<'s> {
@@ -1445,7 +1822,7 @@ pub struct SassWarnAtRule<'s> {
     pub span: Span,
 }
 
-#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq)]
+#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, Walk)]
 #[cfg_attr(feature = "serialize", derive(Serialize))]
 #[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
 pub struct SassWhileAtRule<'s> {
@@ -1454,25 +1831,28 @@ pub struct SassWhileAtRule<'s> {
     pub span: Span,
 }
 
-#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq)]
+#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, Walk)]
 #[cfg_attr(feature = "serialize", derive(Serialize))]
 #[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
+#[ast_node]
 pub struct SelectorList<'s> {
     pub selectors: SmallVec<[ComplexSelector<'s>; 2]>,
     pub span: Span,
 }
 
-#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq)]
+#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, Walk)]
 #[cfg_attr(feature = "serialize", derive(Serialize))]
 #[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
+#[ast_node]
 pub struct SimpleBlock<'s> {
     pub statements: Vec<Statement<'s>>,
     pub span: Span,
 }
 
-#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, EnumAsIs)]
+#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, Walk, EnumAsIs)]
 #[cfg_attr(feature = "serialize", derive(Serialize))]
 #[cfg_attr(feature = "serialize", serde(untagged))]
+#[ast_node]
 pub enum SimpleSelector<'s> {
     Class(ClassSelector<'s>),
     Id(IdSelector<'s>),
@@ -1484,13 +1864,15 @@ pub enum SimpleSelector<'s> {
     SassPlaceholder(SassPlaceholderSelector<'s>),
 }
 
-#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, EnumAsIs)]
+#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, Walk, EnumAsIs)]
 #[cfg_attr(feature = "serialize", derive(Serialize))]
 #[cfg_attr(feature = "serialize", serde(untagged))]
 pub enum Statement<'s> {
     AtRule(AtRule<'s>),
     Declaration(Declaration<'s>),
     KeyframeBlock(KeyframeBlock<'s>),
+    LessGuardedRule(LessGuardedRule<'s>),
+    LessMixinCall(LessMixinCall<'s>),
     LessVariableDeclaration(LessVariableDeclaration<'s>),
     QualifiedRule(QualifiedRule<'s>),
     SassContentAtRule(SassContentAtRule<'s>),
@@ -1511,7 +1893,7 @@ pub enum Statement<'s> {
     SassWhileAtRule(SassWhileAtRule<'s>),
 }
 
-#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq)]
+#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, Walk)]
 #[cfg_attr(feature = "serialize", derive(Serialize))]
 #[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
 pub struct Str<'s> {
@@ -1520,7 +1902,7 @@ pub struct Str<'s> {
     pub span: Span,
 }
 
-#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq)]
+#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, Walk)]
 #[cfg_attr(feature = "serialize", derive(Serialize))]
 #[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
 pub struct StyleCondition<'s> {
@@ -1528,7 +1910,7 @@ pub struct StyleCondition<'s> {
     pub span: Span,
 }
 
-#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, EnumAsIs)]
+#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, Walk, EnumAsIs)]
 #[cfg_attr(feature = "serialize", derive(Serialize))]
 #[cfg_attr(feature = "serialize", serde(untagged))]
 pub enum StyleConditionKind<'s> {
@@ -1538,7 +1920,7 @@ pub enum StyleConditionKind<'s> {
     Not(StyleConditionNot<'s>),
 }
 
-#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq)]
+#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, Walk)]
 #[cfg_attr(feature = "serialize", derive(Serialize))]
 #[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
 pub struct StyleConditionAnd<'s> {
@@ -1547,7 +1929,7 @@ pub struct StyleConditionAnd<'s> {
     pub span: Span,
 }
 
-#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq)]
+#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, Walk)]
 #[cfg_attr(feature = "serialize", derive(Serialize))]
 #[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
 pub struct StyleConditionNot<'s> {
@@ -1556,7 +1938,7 @@ pub struct StyleConditionNot<'s> {
     pub span: Span,
 }
 
-#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq)]
+#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, Walk)]
 #[cfg_attr(feature = "serialize", derive(Serialize))]
 #[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
 pub struct StyleConditionOr<'s> {
@@ -1565,7 +1947,7 @@ pub struct StyleConditionOr<'s> {
     pub span: Span,
 }
 
-#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, EnumAsIs)]
+#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, Walk, EnumAsIs)]
 #[cfg_attr(feature = "serialize", derive(Serialize))]
 #[cfg_attr(feature = "serialize", serde(untagged))]
 pub enum StyleInParens<'s> {
@@ -1573,7 +1955,7 @@ pub enum StyleInParens<'s> {
     Feature(Declaration<'s>),
 }
 
-#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, EnumAsIs)]
+#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, Walk, EnumAsIs)]
 #[cfg_attr(feature = "serialize", derive(Serialize))]
 #[cfg_attr(feature = "serialize", serde(untagged))]
 pub enum StyleQuery<'s> {
@@ -1581,15 +1963,16 @@ pub enum StyleQuery<'s> {
     Feature(Declaration<'s>),
 }
 
-#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq)]
+#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, Walk)]
 #[cfg_attr(feature = "serialize", derive(Serialize))]
 #[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
+#[ast_node]
 pub struct Stylesheet<'s> {
     pub statements: Vec<Statement<'s>>,
     pub span: Span,
 }
 
-#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq)]
+#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, Walk)]
 #[cfg_attr(feature = "serialize", derive(Serialize))]
 #[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
 pub struct SupportsAnd<'s> {
@@ -1598,7 +1981,7 @@ pub struct SupportsAnd<'s> {
     pub span: Span,
 }
 
-#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq)]
+#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, Walk)]
 #[cfg_attr(feature = "serialize", derive(Serialize))]
 #[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
 pub struct SupportsCondition<'s> {
@@ -1606,7 +1989,7 @@ pub struct SupportsCondition<'s> {
     pub span: Span,
 }
 
-#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, EnumAsIs)]
+#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, Walk, EnumAsIs)]
 #[cfg_attr(feature = "serialize", derive(Serialize))]
 #[cfg_attr(feature = "serialize", serde(untagged))]
 pub enum SupportsConditionKind<'s> {
@@ -1616,7 +1999,7 @@ pub enum SupportsConditionKind<'s> {
     SupportsInParens(SupportsInParens<'s>),
 }
 
-#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq)]
+#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, Walk)]
 #[cfg_attr(feature = "serialize", derive(Serialize))]
 #[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
 pub struct SupportsDecl<'s> {
@@ -1624,7 +2007,7 @@ pub struct SupportsDecl<'s> {
     pub span: Span,
 }
 
-#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, EnumAsIs)]
+#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, Walk, EnumAsIs)]
 #[cfg_attr(feature = "serialize", derive(Serialize))]
 #[cfg_attr(feature = "serialize", serde(untagged))]
 pub enum SupportsInParens<'s> {
@@ -1632,7 +2015,7 @@ pub enum SupportsInParens<'s> {
     Feature(Box<SupportsDecl<'s>>),
 }
 
-#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq)]
+#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, Walk)]
 #[cfg_attr(feature = "serialize", derive(Serialize))]
 #[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
 pub struct SupportsNot<'s> {
@@ -1641,7 +2024,7 @@ pub struct SupportsNot<'s> {
     pub span: Span,
 }
 
-#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq)]
+#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, Walk)]
 #[cfg_attr(feature = "serialize", derive(Serialize))]
 #[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
 pub struct SupportsOr<'s> {
@@ -1650,7 +2033,7 @@ pub struct SupportsOr<'s> {
     pub span: Span,
 }
 
-#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq)]
+#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, Walk)]
 #[cfg_attr(feature = "serialize", derive(Serialize))]
 #[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
 pub struct TagNameSelector<'s> {
@@ -1658,7 +2041,29 @@ pub struct TagNameSelector<'s> {
     pub span: Span,
 }
 
-#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq)]
+/// Tailwind CSS `theme()` function, such as `theme(colors.red.500, blue)`
+/// or `theme('colors.red.500')`.
+#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, Walk)]
+#[cfg_attr(feature = "serialize", derive(Serialize))]
+#[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
+pub struct ThemeFunction<'s> {
+    pub name: Ident<'s>,
+    pub path: Vec<ThemeKeySegment<'s>>,
+    pub fallback: Option<ComponentValues<'s>>,
+    pub span: Span,
+}
+
+#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, Walk, EnumAsIs)]
+#[cfg_attr(feature = "serialize", derive(Serialize))]
+#[cfg_attr(feature = "serialize", serde(untagged))]
+pub enum ThemeKeySegment<'s> {
+    Ident(Ident<'s>),
+    Number(Number<'s>),
+    /// Quoted path like `'colors.red.500'`, which is the only segment of that path.
+    Str(Str<'s>),
+}
+
+#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, Walk)]
 #[cfg_attr(feature = "serialize", derive(Serialize))]
 #[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
 pub struct TokenSeq<'s> {
@@ -1666,7 +2071,7 @@ pub struct TokenSeq<'s> {
     pub span: Span,
 }
 
-#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, EnumAsIs)]
+#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, Walk, EnumAsIs)]
 #[cfg_attr(feature = "serialize", derive(Serialize))]
 #[cfg_attr(feature = "serialize", serde(untagged))]
 pub enum TypeSelector<'s> {
@@ -1674,7 +2079,7 @@ pub enum TypeSelector<'s> {
     Universal(UniversalSelector<'s>),
 }
 
-#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq)]
+#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, Walk)]
 #[cfg_attr(feature = "serialize", derive(Serialize))]
 #[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
 pub struct UnicodeRange<'s> {
@@ -1686,7 +2091,7 @@ pub struct UnicodeRange<'s> {
     pub span: Span,
 }
 
-#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq)]
+#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, Walk)]
 #[cfg_attr(feature = "serialize", derive(Serialize))]
 #[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
 pub struct UniversalSelector<'s> {
@@ -1694,7 +2099,7 @@ pub struct UniversalSelector<'s> {
     pub span: Span,
 }
 
-#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq)]
+#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, Walk)]
 #[cfg_attr(feature = "serialize", derive(Serialize))]
 #[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
 pub struct UnknownDimension<'s> {
@@ -1703,7 +2108,7 @@ pub struct UnknownDimension<'s> {
     pub span: Span,
 }
 
-#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq)]
+#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, Walk)]
 #[cfg_attr(feature = "serialize", derive(Serialize))]
 #[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
 pub struct UnquotedFontFamilyName<'s> {
@@ -1711,7 +2116,15 @@ pub struct UnquotedFontFamilyName<'s> {
     pub span: Span,
 }
 
-#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq)]
+#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, Walk)]
+#[cfg_attr(feature = "serialize", derive(Serialize))]
+#[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
+pub struct UnquotedFontFormat<'s> {
+    pub idents: Vec<InterpolableIdent<'s>>,
+    pub span: Span,
+}
+
+#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, Walk)]
 #[cfg_attr(feature = "serialize", derive(Serialize))]
 #[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
 pub struct Url<'s> {
@@ -1721,7 +2134,7 @@ pub struct Url<'s> {
     pub span: Span,
 }
 
-#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, EnumAsIs)]
+#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, Walk, EnumAsIs)]
 #[cfg_attr(feature = "serialize", derive(Serialize))]
 #[cfg_attr(feature = "serialize", serde(untagged))]
 pub enum UrlModifier<'s> {
@@ -1730,7 +2143,7 @@ pub enum UrlModifier<'s> {
 }
 
 /// `)` is excluded
-#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq)]
+#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, Walk)]
 #[cfg_attr(feature = "serialize", derive(Serialize))]
 #[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
 pub struct UrlRaw<'s> {
@@ -1739,7 +2152,7 @@ pub struct UrlRaw<'s> {
     pub span: Span,
 }
 
-#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, EnumAsIs)]
+#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, Walk, EnumAsIs)]
 #[cfg_attr(feature = "serialize", derive(Serialize))]
 #[cfg_attr(feature = "serialize", serde(untagged))]
 pub enum UrlValue<'s> {
@@ -1748,7 +2161,7 @@ pub enum UrlValue<'s> {
     Str(InterpolableStr<'s>),
 }
 
-#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq)]
+#[derive(Clone, Debug, Spanned, PartialEq, SpanIgnoredEq, Walk)]
 #[cfg_attr(feature = "serialize", derive(Serialize))]
 #[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
 pub struct WqName<'s> {
@@ -0,0 +1,288 @@
+use crate::{
+    ast::*,
+    pos::{Span, Spanned},
+    tokenizer::TokenWithSpan,
+    util::CowStr,
+};
+use raffia_macro::{EnumAsIs, Spanned};
+use smallvec::SmallVec;
+
+/// Reference to one of the major AST nodes,
+/// which allows handling different kinds of nodes uniformly.
+#[derive(Clone, Copy, Debug, Spanned, EnumAsIs)]
+pub enum AstNode<'a, 's> {
+    AtRule(&'a AtRule<'s>),
+    ComplexSelector(&'a ComplexSelector<'s>),
+    ComponentValue(&'a ComponentValue<'s>),
+    CompoundSelector(&'a CompoundSelector<'s>),
+    Declaration(&'a Declaration<'s>),
+    KeyframeBlock(&'a KeyframeBlock<'s>),
+    QualifiedRule(&'a QualifiedRule<'s>),
+    SelectorList(&'a SelectorList<'s>),
+    SimpleBlock(&'a SimpleBlock<'s>),
+    SimpleSelector(&'a SimpleSelector<'s>),
+    Stylesheet(&'a Stylesheet<'s>),
+}
+
+/// Mutable reference to one of the major AST nodes.
+///
+/// This is the counterpart of [`AstNode`] used by [`WalkMut`].
+#[derive(Debug, Spanned, EnumAsIs)]
+pub enum AstNodeMut<'a, 's> {
+    AtRule(&'a mut AtRule<'s>),
+    ComplexSelector(&'a mut ComplexSelector<'s>),
+    ComponentValue(&'a mut ComponentValue<'s>),
+    CompoundSelector(&'a mut CompoundSelector<'s>),
+    Declaration(&'a mut Declaration<'s>),
+    KeyframeBlock(&'a mut KeyframeBlock<'s>),
+    QualifiedRule(&'a mut QualifiedRule<'s>),
+    SelectorList(&'a mut SelectorList<'s>),
+    SimpleBlock(&'a mut SimpleBlock<'s>),
+    SimpleSelector(&'a mut SimpleSelector<'s>),
+    Stylesheet(&'a mut Stylesheet<'s>),
+}
+
+/// Walk through an AST node and its descendants.
+pub trait Walk<'s> {
+    /// Call `f` with every [`AstNode`] in the subtree, in source order.
+    ///
+    /// If `f` returns `false`, descendants of that node will be skipped.
+    fn walk<'a, F>(&'a self, f: &mut F)
+    where
+        F: FnMut(AstNode<'a, 's>) -> bool,
+        's: 'a;
+}
+
+/// Walk through an AST node and its descendants, allowing them to be modified.
+pub trait WalkMut<'s> {
+    /// Call `f` with every [`AstNodeMut`] in the subtree, in source order.
+    ///
+    /// If `f` returns `false`, descendants of that node will be skipped.
+    fn walk_mut<F>(&mut self, f: &mut F)
+    where
+        F: FnMut(AstNodeMut<'_, 's>) -> bool;
+}
+
+macro_rules! impl_walk_for_leaf {
+    ($($ty:ty),*) => {
+        $(
+            impl<'s> Walk<'s> for $ty {
+                #[inline]
+                fn walk<'a, F>(&'a self, _: &mut F)
+                where
+                    F: FnMut(AstNode<'a, 's>) -> bool,
+                    's: 'a,
+                {
+                }
+            }
+
+            impl<'s> WalkMut<'s> for $ty {
+                #[inline]
+                fn walk_mut<F>(&mut self, _: &mut F)
+                where
+                    F: FnMut(AstNodeMut<'_, 's>) -> bool,
+                {
+                }
+            }
+        )*
+    };
+}
+
+impl_walk_for_leaf!(
+    &str,
+    CowStr<'_>,
+    f32,
+    i32,
+    u32,
+    bool,
+    char,
+    TokenWithSpan<'_>
+);
+
+impl<'s, T> Walk<'s> for Vec<T>
+where
+    T: Walk<'s>,
+{
+    #[inline]
+    fn walk<'a, F>(&'a self, f: &mut F)
+    where
+        F: FnMut(AstNode<'a, 's>) -> bool,
+        's: 'a,
+    {
+        self.iter().for_each(|item| item.walk(f));
+    }
+}
+
+impl<'s, T, const N: usize> Walk<'s> for SmallVec<[T; N]>
+where
+    T: Walk<'s>,
+{
+    #[inline]
+    fn walk<'a, F>(&'a self, f: &mut F)
+    where
+        F: FnMut(AstNode<'a, 's>) -> bool,
+        's: 'a,
+    {
+        self.iter().for_each(|item| item.walk(f));
+    }
+}
+
+impl<'s, T> Walk<'s> for Option<T>
+where
+    T: Walk<'s>,
+{
+    #[inline]
+    fn walk<'a, F>(&'a self, f: &mut F)
+    where
+        F: FnMut(AstNode<'a, 's>) -> bool,
+        's: 'a,
+    {
+        if let Some(value) = self {
+            value.walk(f);
+        }
+    }
+}
+
+impl<'s, T> Walk<'s> for Box<T>
+where
+    T: Walk<'s>,
+{
+    #[inline]
+    fn walk<'a, F>(&'a self, f: &mut F)
+    where
+        F: FnMut(AstNode<'a, 's>) -> bool,
+        's: 'a,
+    {
+        (**self).walk(f);
+    }
+}
+
+impl<'s, T> WalkMut<'s> for Vec<T>
+where
+    T: WalkMut<'s>,
+{
+    #[inline]
+    fn walk_mut<F>(&mut self, f: &mut F)
+    where
+        F: FnMut(AstNodeMut<'_, 's>) -> bool,
+    {
+        self.iter_mut().for_each(|item| item.walk_mut(f));
+    }
+}
+
+impl<'s, T, const N: usize> WalkMut<'s> for SmallVec<[T; N]>
+where
+    T: WalkMut<'s>,
+{
+    #[inline]
+    fn walk_mut<F>(&mut self, f: &mut F)
+    where
+        F: FnMut(AstNodeMut<'_, 's>) -> bool,
+    {
+        self.iter_mut().for_each(|item| item.walk_mut(f));
+    }
+}
+
+impl<'s, T> WalkMut<'s> for Option<T>
+where
+    T: WalkMut<'s>,
+{
+    #[inline]
+    fn walk_mut<F>(&mut self, f: &mut F)
+    where
+        F: FnMut(AstNodeMut<'_, 's>) -> bool,
+    {
+        if let Some(value) = self {
+            value.walk_mut(f);
+        }
+    }
+}
+
+impl<'s, T> WalkMut<'s> for Box<T>
+where
+    T: WalkMut<'s>,
+{
+    #[inline]
+    fn walk_mut<F>(&mut self, f: &mut F)
+    where
+        F: FnMut(AstNodeMut<'_, 's>) -> bool,
+    {
+        (**self).walk_mut(f);
+    }
+}
+
+/// Visitor for rewriting selectors in place.
+///
+/// Every hook does nothing by default,
+/// so implementors only need to override the ones they're interested in.
+///
+/// ```rust
+/// use raffia::{ast::*, Parser, Syntax, VisitMut};
+///
+/// struct Prefixer;
+/// impl<'s> VisitMut<'s> for Prefixer {
+///     fn visit_mut_class_selector(&mut self, class: &mut ClassSelector<'s>) {
+///         if let InterpolableIdent::Literal(ident) = &mut class.name {
+///             ident.name = format!("x-{}", ident.name).into();
+///         }
+///     }
+/// }
+///
+/// let mut parser = Parser::new(".a .b {}", Syntax::Css);
+/// let mut stylesheet = parser.parse::<Stylesheet>().unwrap();
+/// Prefixer.visit_mut(&mut stylesheet);
+/// ```
+pub trait VisitMut<'s> {
+    fn visit_mut_class_selector(&mut self, _: &mut ClassSelector<'s>) {}
+
+    fn visit_mut_id_selector(&mut self, _: &mut IdSelector<'s>) {}
+
+    fn visit_mut_type_selector(&mut self, _: &mut TypeSelector<'s>) {}
+
+    /// Traverse `node` and its descendants, calling hooks above on each matched node.
+    fn visit_mut<N>(&mut self, node: &mut N)
+    where
+        N: WalkMut<'s>,
+        Self: Sized,
+    {
+        node.walk_mut(&mut |node| {
+            if let AstNodeMut::SimpleSelector(selector) = node {
+                match selector {
+                    SimpleSelector::Class(class) => self.visit_mut_class_selector(class),
+                    SimpleSelector::Id(id) => self.visit_mut_id_selector(id),
+                    SimpleSelector::Type(ty) => self.visit_mut_type_selector(ty),
+                    _ => {}
+                }
+            }
+            true
+        });
+    }
+}
+
+/// Find the innermost [`AstNode`] whose span contains the given offset.
+///
+/// ```rust
+/// use raffia::{ast::Stylesheet, node_at_offset, Parser, Syntax};
+///
+/// let mut parser = Parser::new("a { color: red }", Syntax::Css);
+/// let stylesheet = parser.parse::<Stylesheet>().unwrap();
+/// let node = node_at_offset(&stylesheet, 12).unwrap();
+/// assert!(node.is_component_value());
+/// ```
+pub fn node_at_offset<'a, 's, N>(root: &'a N, offset: usize) -> Option<AstNode<'a, 's>>
+where
+    N: Walk<'s>,
+    's: 'a,
+{
+    let mut found = None;
+    root.walk(&mut |node| {
+        let Span { start, end } = node.span();
+        if *start <= offset && offset < *end {
+            found = Some(node);
+            true
+        } else {
+            false
+        }
+    });
+    found
+}
@@ -13,3 +13,53 @@ pub enum Syntax {
     Sass,
     Less,
 }
+
+/// Unit of indentation in indented Sass syntax.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serialize", serde(rename_all = "camelCase"))]
+pub enum IndentUnit {
+    /// Indent with the given number of spaces.
+    Spaces(u16),
+    /// Indent with tabs.
+    Tabs,
+}
+
+#[derive(Clone, Debug, Default)]
+pub(crate) struct ParserOptions {
+    /// Abort on invalid query in media query list instead of recovering it.
+    pub(crate) strict_media_query_list: bool,
+    /// Abort on declaration without value or with values after `!important`
+    /// instead of recovering it.
+    pub(crate) strict_declaration_value: bool,
+    /// Abort on CSS-wide keyword mixed with other values in declaration.
+    pub(crate) strict_css_wide_keyword: bool,
+    /// Abort on reserved keyword used as keyframes name instead of recovering it.
+    pub(crate) strict_keyframes_name: bool,
+    /// Abort on reserved keyword used as layer name instead of recovering it.
+    pub(crate) strict_layer_name: bool,
+    /// Abort on selector list or complex selector in `:not()` as Selectors Level 3.
+    pub(crate) strict_not_pseudo_class: bool,
+    /// Abort on invalid `inherits` or missing `initial-value` descriptor in `@property`.
+    pub(crate) strict_property_rule: bool,
+    /// Abort on invalid keyword as value of discrete media feature.
+    pub(crate) strict_media_feature_value: bool,
+    /// Abort on block comment which isn't closed before end of file.
+    pub(crate) strict_block_comment: bool,
+    /// Abort on invalid keyword as value of `font-display` descriptor in `@font-face`.
+    pub(crate) strict_font_face_descriptor: bool,
+    /// Keep source text of at-rule prelude in AST.
+    pub(crate) preserve_raw: bool,
+    /// Report deprecated media features as recoverable errors.
+    pub(crate) warn_deprecated_media_features: bool,
+    /// Accept declaration without parens in `@supports` condition.
+    pub(crate) allow_bare_supports_declaration: bool,
+    /// Recover unterminated string instead of aborting.
+    pub(crate) allow_bad_string: bool,
+    /// Decode invalid escape literally and record a recoverable error instead of aborting.
+    pub(crate) allow_invalid_escape: bool,
+    /// Abort on indentation which doesn't consist of this unit in indented Sass syntax.
+    pub(crate) sass_indent_unit: Option<IndentUnit>,
+    /// Parse Tailwind CSS specific syntax, such as `theme()` function.
+    pub(crate) tailwind: bool,
+}
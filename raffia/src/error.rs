@@ -23,11 +23,13 @@ pub enum ErrorKind {
 
     UnknownToken,
     InvalidNumber,
+    NumberOverflow,
     InvalidEscape,
     InvalidHash,
     ExpectRightBraceForLessVar,
     UnexpectedLinebreak,
     UnexpectedEof,
+    UnterminatedBlockComment,
 
     UnexpectedWhitespace,
     ExpectSimpleSelector,
@@ -54,12 +56,24 @@ pub enum ErrorKind {
     ExpectStyleConditionNot,
     ExpectStyleQuery,
     ExpectSassKeyword(&'static str),
+    ExpectLessKeyword(&'static str),
     InvalidAnPlusB,
     ExpectInteger,
     ExpectUnsignedInteger,
     ExpectImportantAnnotation,
     ExpectSassUseNamespace,
     InvalidUnicodeRange,
+    SassArbitraryArgumentNotLast,
+    DanglingCombinator,
+    InvalidUnquotedUrl,
+    ExpectSingleSimpleSelector,
+    InvalidPropertyInherits,
+    MissingPropertyInitialValue,
+    MissingPropertyDescriptor(&'static str),
+    InconsistentIndentation,
+    InvalidMediaFeatureValue,
+    InvalidFontFaceDescriptor,
+    MixedSupportsConditionOperators,
 
     TryParseError,
     CSSWideKeywordDisallowed,
@@ -72,6 +86,16 @@ pub enum ErrorKind {
     ReturnOutsideFunction,
     MaxCodePointExceeded,
     UnicodeRangeStartGreaterThanEnd,
+    InvalidMediaQuery,
+    SassPositionalArgumentAfterKeyword,
+    EmptyDeclarationValue,
+    ReservedKeyframesName,
+    UnknownLessImportOption,
+    DeprecatedMediaFeature,
+    ImportantNotLast,
+    UnterminatedString,
+    CSSWideKeywordNotAlone,
+    InconsistentMediaFeatureRange,
 }
 
 impl Display for ErrorKind {
@@ -83,11 +107,13 @@ impl Display for ErrorKind {
 
             Self::UnknownToken => write!(f, "unknown token"),
             Self::InvalidNumber => write!(f, "invalid number"),
+            Self::NumberOverflow => write!(f, "number is too large"),
             Self::InvalidEscape => write!(f, "invalid escape"),
             Self::InvalidHash => write!(f, "invalid hash token"),
             Self::ExpectRightBraceForLessVar => write!(f, "`}}` for Less variable is expected"),
             Self::UnexpectedLinebreak => write!(f, "unexpected linebreak"),
             Self::UnexpectedEof => write!(f, "unexpected end of file"),
+            Self::UnterminatedBlockComment => write!(f, "unterminated block comment"),
 
             Self::UnexpectedWhitespace => write!(f, "unexpected whitespace"),
             Self::ExpectSimpleSelector => write!(f, "simple selector is expected"),
@@ -116,6 +142,7 @@ impl Display for ErrorKind {
             Self::ExpectStyleConditionNot => write!(f, "style condition `not` is expected"),
             Self::ExpectStyleQuery => write!(f, "style query is expected"),
             Self::ExpectSassKeyword(keyword) => write!(f, "Sass keyword `{keyword}` is expected"),
+            Self::ExpectLessKeyword(keyword) => write!(f, "Less keyword `{keyword}` is expected"),
             Self::InvalidAnPlusB => write!(f, "invalid An+B syntax"),
             Self::ExpectInteger => write!(f, "an integer is expected"),
             Self::ExpectUnsignedInteger => write!(f, "unsigned integer is expected"),
@@ -124,6 +151,38 @@ impl Display for ErrorKind {
                 write!(f, "`*` or ident for Sass namespace is expected")
             }
             Self::InvalidUnicodeRange => write!(f, "invalid unicode range"),
+            Self::SassArbitraryArgumentNotLast => {
+                write!(f, "arbitrary argument must be the last argument")
+            }
+            Self::DanglingCombinator => {
+                write!(f, "combinator must be followed by compound selector")
+            }
+            Self::InvalidUnquotedUrl => write!(
+                f,
+                "unquoted url can't contain whitespaces, try wrapping it with quotes"
+            ),
+            Self::ExpectSingleSimpleSelector => {
+                write!(f, "only single simple selector is allowed")
+            }
+            Self::InvalidPropertyInherits => {
+                write!(
+                    f,
+                    "value of `inherits` descriptor must be `true` or `false`"
+                )
+            }
+            Self::MissingPropertyInitialValue => write!(
+                f,
+                "`initial-value` descriptor is required unless `syntax` is universal"
+            ),
+            Self::MissingPropertyDescriptor(name) => {
+                write!(f, "`{name}` descriptor is required")
+            }
+            Self::InconsistentIndentation => write!(f, "inconsistent indentation"),
+            Self::InvalidMediaFeatureValue => write!(f, "invalid value for media feature"),
+            Self::InvalidFontFaceDescriptor => write!(f, "invalid value for font face descriptor"),
+            Self::MixedSupportsConditionOperators => {
+                write!(f, "`and` and `or` can't be mixed without parens")
+            }
 
             Self::TryParseError => unreachable!(),
             Self::CSSWideKeywordDisallowed => {
@@ -144,6 +203,29 @@ impl Display for ErrorKind {
             Self::UnicodeRangeStartGreaterThanEnd => {
                 write!(f, "unicode range start value can't greater than end value")
             }
+            Self::InvalidMediaQuery => write!(f, "invalid media query"),
+            Self::SassPositionalArgumentAfterKeyword => {
+                write!(f, "positional argument can't follow keyword argument")
+            }
+            Self::EmptyDeclarationValue => write!(f, "declaration value is empty"),
+            Self::ReservedKeyframesName => {
+                write!(f, "reserved keyword can't be used as keyframes name")
+            }
+            Self::UnknownLessImportOption => write!(f, "unknown Less import option"),
+            Self::DeprecatedMediaFeature => write!(f, "media feature is deprecated"),
+            Self::ImportantNotLast => {
+                write!(f, "`!important` must be at the end of declaration value")
+            }
+            Self::UnterminatedString => write!(f, "unterminated string"),
+            Self::CSSWideKeywordNotAlone => {
+                write!(f, "CSS-wide keyword must be the only value of declaration")
+            }
+            Self::InconsistentMediaFeatureRange => {
+                write!(
+                    f,
+                    "comparisons of media feature range must be in the same direction"
+                )
+            }
         }
     }
 }
@@ -100,13 +100,15 @@
 //!
 //! Note that Raffia only supports serialization. Deserialization isn't supported.
 
-pub use config::Syntax;
+pub use ast_node::{node_at_offset, AstNode, AstNodeMut, VisitMut, Walk, WalkMut};
+pub use config::{IndentUnit, Syntax};
 pub use parser::{Parse, Parser, ParserBuilder};
-pub use pos::{Span, Spanned};
+pub use pos::{LineIndex, Span, Spanned};
 pub use span_ignored_eq::SpanIgnoredEq;
-pub use tokenizer::token;
+pub use tokenizer::{token, tokenize, Tokenizer, TokenizerCheckpoint, Tokens};
 
 pub mod ast;
+mod ast_node;
 mod config;
 pub mod error;
 mod parser;
@@ -0,0 +1,99 @@
+use super::Parser;
+use crate::{
+    ast::*,
+    eat,
+    error::{Error, ErrorKind, PResult},
+    peek,
+    pos::Spanned,
+    tokenizer::Token,
+    Parse,
+};
+
+// https://drafts.csswg.org/css-fonts/#src-desc
+impl<'cmt, 's: 'cmt> Parse<'cmt, 's> for FontFaceSrc<'s> {
+    fn parse(input: &mut Parser<'cmt, 's>) -> PResult<Self> {
+        let first = input.parse::<FontFaceSrcEntry>()?;
+        let mut span = first.span.clone();
+
+        let mut entries = vec![first];
+        while eat!(input, Comma).is_some() {
+            entries.push(input.parse()?);
+        }
+        match &peek!(input).token {
+            Token::RBrace(..)
+            | Token::Semicolon(..)
+            | Token::Dedent(..)
+            | Token::Linebreak(..)
+            | Token::Exclamation(..)
+            | Token::Eof(..) => {}
+            _ => {
+                return Err(Error {
+                    kind: ErrorKind::TryParseError,
+                    span: peek!(input).span.clone(),
+                });
+            }
+        }
+
+        if let Some(last) = entries.last() {
+            span.end = last.span.end;
+        }
+        Ok(FontFaceSrc { entries, span })
+    }
+}
+
+impl<'cmt, 's: 'cmt> Parse<'cmt, 's> for FontFaceSrcEntry<'s> {
+    fn parse(input: &mut Parser<'cmt, 's>) -> PResult<Self> {
+        let source = match input.parse::<ComponentValue>()? {
+            ComponentValue::Url(url) => FontFaceSrcSource::Url(url),
+            ComponentValue::Function(function) if is_function_named(&function, "local") => {
+                FontFaceSrcSource::Local(function)
+            }
+            value => {
+                return Err(Error {
+                    kind: ErrorKind::ExpectUrl,
+                    span: value.span().clone(),
+                });
+            }
+        };
+        let mut span = source.span().clone();
+
+        let format = input
+            .try_parse(|parser| match parser.parse::<ComponentValue>()? {
+                ComponentValue::FormatFunction(format) => Ok(format),
+                value => Err(Error {
+                    kind: ErrorKind::TryParseError,
+                    span: value.span().clone(),
+                }),
+            })
+            .ok();
+        if let Some(format) = &format {
+            span.end = format.span.end;
+        }
+
+        let tech = input
+            .try_parse(|parser| match parser.parse::<ComponentValue>()? {
+                ComponentValue::Function(function) if is_function_named(&function, "tech") => {
+                    Ok(function)
+                }
+                value => Err(Error {
+                    kind: ErrorKind::TryParseError,
+                    span: value.span().clone(),
+                }),
+            })
+            .ok();
+        if let Some(tech) = &tech {
+            span.end = tech.span.end;
+        }
+
+        Ok(FontFaceSrcEntry {
+            source,
+            format,
+            tech,
+            span,
+        })
+    }
+}
+
+fn is_function_named(function: &Function, name: &str) -> bool {
+    matches!(&function.name, InterpolableIdent::Literal(ident) if ident.name.eq_ignore_ascii_case(name))
+}
@@ -1,22 +1,36 @@
 use super::Parser;
 use crate::{
     ast::*,
-    bump,
+    bump, eat,
     error::{Error, ErrorKind, PResult},
     expect, expect_without_ws_or_comments, peek,
     pos::{Span, Spanned},
     tokenizer::{Token, TokenWithSpan},
-    Parse,
+    Parse, Syntax,
 };
 
 // https://www.w3.org/TR/css-cascade-5/#at-import
 impl<'cmt, 's: 'cmt> Parse<'cmt, 's> for ImportPrelude<'s> {
     fn parse(input: &mut Parser<'cmt, 's>) -> PResult<Self> {
+        let less_options = match &peek!(input).token {
+            Token::LParen(..) if input.syntax == Syntax::Less => {
+                Some(input.parse::<LessImportOptions>()?)
+            }
+            _ => None,
+        };
+
         let href = match &peek!(input).token {
             Token::Str(..) | Token::StrTemplate(..) => input.parse().map(ImportPreludeHref::Str)?,
             _ => input.parse().map(ImportPreludeHref::Url)?,
         };
-        let mut span = href.span().clone();
+        let mut span = if let Some(less_options) = &less_options {
+            Span {
+                start: less_options.span.start,
+                end: href.span().end,
+            }
+        } else {
+            href.span().clone()
+        };
 
         let layer = match &peek!(input).token {
             Token::Ident(ident) if ident.name().eq_ignore_ascii_case("layer") => {
@@ -81,6 +95,7 @@ impl<'cmt, 's: 'cmt> Parse<'cmt, 's> for ImportPrelude<'s> {
         };
 
         Ok(ImportPrelude {
+            less_options,
             href,
             layer,
             supports: supports.ok(),
@@ -89,3 +104,42 @@ impl<'cmt, 's: 'cmt> Parse<'cmt, 's> for ImportPrelude<'s> {
         })
     }
 }
+
+// https://lesscss.org/features/#import-atrules-feature-import-options
+impl<'cmt, 's: 'cmt> Parse<'cmt, 's> for LessImportOptions<'s> {
+    fn parse(input: &mut Parser<'cmt, 's>) -> PResult<Self> {
+        let start = expect!(input, LParen).1.start;
+
+        let mut names = Vec::with_capacity(1);
+        loop {
+            let name = input.parse::<Ident>()?;
+            if ![
+                "reference",
+                "inline",
+                "less",
+                "css",
+                "once",
+                "multiple",
+                "optional",
+            ]
+            .iter()
+            .any(|option| name.name.eq_ignore_ascii_case(option))
+            {
+                input.recoverable_errors.push(Error {
+                    kind: ErrorKind::UnknownLessImportOption,
+                    span: name.span.clone(),
+                });
+            }
+            names.push(name);
+            if eat!(input, Comma).is_none() {
+                break;
+            }
+        }
+
+        let end = expect!(input, RParen).1.end;
+        Ok(LessImportOptions {
+            names,
+            span: Span { start, end },
+        })
+    }
+}
@@ -1,6 +1,7 @@
-use super::Parser;
+use super::{Parser, ParserState};
 use crate::{
     ast::*,
+    config::Syntax,
     eat,
     error::{Error, ErrorKind, PResult},
     peek,
@@ -22,7 +23,12 @@ impl<'cmt, 's: 'cmt> Parse<'cmt, 's> for KeyframeBlock<'s> {
             selectors.push(input.parse()?);
         }
 
-        let block = input.parse::<SimpleBlock>()?;
+        let block = input
+            .with_state(ParserState {
+                in_keyframes: false,
+                ..input.state.clone()
+            })
+            .parse::<SimpleBlock>()?;
         let span = Span {
             start,
             end: block.span.end,
@@ -68,12 +74,18 @@ impl<'cmt, 's: 'cmt> Parse<'cmt, 's> for KeyframesName<'s> {
                 match &ident {
                     InterpolableIdent::Literal(ident)
                         if util::is_css_wide_keyword(&ident.name)
-                            || ident.name.eq_ignore_ascii_case("default") =>
+                            || ident.name.eq_ignore_ascii_case("default")
+                            || ident.name.eq_ignore_ascii_case("none") =>
                     {
-                        input.recoverable_errors.push(Error {
-                            kind: ErrorKind::CSSWideKeywordDisallowed,
+                        let error = Error {
+                            kind: ErrorKind::ReservedKeyframesName,
                             span: ident.span.clone(),
-                        });
+                        };
+                        if input.options.strict_keyframes_name {
+                            return Err(error);
+                        } else {
+                            input.recoverable_errors.push(error);
+                        }
                     }
                     _ => {}
                 }
@@ -86,6 +98,18 @@ impl<'cmt, 's: 'cmt> Parse<'cmt, 's> for KeyframesName<'s> {
 
 impl<'cmt, 's: 'cmt> Parser<'cmt, 's> {
     pub(super) fn parse_keyframes_blocks(&mut self) -> PResult<SimpleBlock<'s>> {
+        // keyframe blocks may be generated by control flow at-rules like `@for` in Sass
+        if matches!(self.syntax, Syntax::Scss | Syntax::Sass) {
+            return self
+                .with_state(ParserState {
+                    in_keyframes: true,
+                    ..self.state.clone()
+                })
+                .parse_simple_block_with(|parser| {
+                    parser.parse_statements(/* is_top_level */ false)
+                });
+        }
+
         self.parse_simple_block_with(|parser| {
             let mut statements = Vec::with_capacity(3);
             while let Token::Percentage(..)
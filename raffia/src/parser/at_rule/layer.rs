@@ -2,7 +2,7 @@ use super::Parser;
 use crate::{
     ast::*,
     bump,
-    error::{Error, PResult},
+    error::{Error, ErrorKind, PResult},
     peek,
     pos::{Span, Spanned},
     tokenizer::{Token, TokenWithSpan},
@@ -38,10 +38,15 @@ impl<'cmt, 's: 'cmt> Parse<'cmt, 's> for LayerName<'s> {
             _ => false,
         });
         if let Some(invalid_ident) = invalid_ident {
-            input.recoverable_errors.push(Error {
-                kind: crate::error::ErrorKind::CSSWideKeywordDisallowed,
+            let error = Error {
+                kind: ErrorKind::CSSWideKeywordDisallowed,
                 span: invalid_ident.span().clone(),
-            });
+            };
+            if input.options.strict_layer_name {
+                return Err(error);
+            } else {
+                input.recoverable_errors.push(error);
+            }
         }
 
         let span = Span { start, end };
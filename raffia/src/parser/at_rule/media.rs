@@ -6,7 +6,7 @@ use crate::{
     expect, peek,
     pos::{Span, Spanned},
     tokenizer::{Token, TokenWithSpan},
-    util::LastOfNonEmpty,
+    util::{LastOfNonEmpty, PairedToken},
     Parse,
 };
 use smallvec::smallvec;
@@ -15,7 +15,7 @@ impl<'cmt, 's: 'cmt> Parse<'cmt, 's> for MediaAnd<'s> {
     fn parse(input: &mut Parser<'cmt, 's>) -> PResult<Self> {
         let keyword = input.parse::<Ident>()?;
         if keyword.name.eq_ignore_ascii_case("and") {
-            let media_in_parens = input.parse::<MediaInParens>()?;
+            let media_in_parens = input.parse_media_in_parens_or_sass_interpolation()?;
             let span = Span {
                 start: keyword.span.start,
                 end: media_in_parens.span().end,
@@ -37,7 +37,7 @@ impl<'cmt, 's: 'cmt> Parse<'cmt, 's> for MediaAnd<'s> {
 impl<'cmt, 's: 'cmt> Parse<'cmt, 's> for MediaFeature<'s> {
     fn parse(input: &mut Parser<'cmt, 's>) -> PResult<Self> {
         let left = input.parse_media_feature_value()?;
-        if let ComponentValue::InterpolableIdent(ident) = left {
+        let feature = if let ComponentValue::InterpolableIdent(ident) = left {
             match &peek!(input).token {
                 Token::Colon(..) => input
                     .parse_media_feature_plain(ident)
@@ -59,7 +59,12 @@ impl<'cmt, 's: 'cmt> Parse<'cmt, 's> for MediaFeature<'s> {
             }
         } else {
             input.parse_media_feature_range_or_range_interval(left)
+        }?;
+
+        if input.options.warn_deprecated_media_features {
+            input.check_deprecated_media_feature(&feature);
         }
+        Ok(feature)
     }
 }
 
@@ -151,7 +156,7 @@ impl<'cmt, 's: 'cmt> Parse<'cmt, 's> for MediaOr<'s> {
     fn parse(input: &mut Parser<'cmt, 's>) -> PResult<Self> {
         let keyword = input.parse::<Ident>()?;
         if keyword.name.eq_ignore_ascii_case("or") {
-            let media_in_parens = input.parse::<MediaInParens>()?;
+            let media_in_parens = input.parse_media_in_parens_or_sass_interpolation()?;
             let span = Span {
                 start: keyword.span.start,
                 end: media_in_parens.span().end,
@@ -185,12 +190,26 @@ impl<'cmt, 's: 'cmt> Parse<'cmt, 's> for MediaQuery<'s> {
 // https://www.w3.org/TR/mediaqueries-4/#mq-syntax
 impl<'cmt, 's: 'cmt> Parse<'cmt, 's> for MediaQueryList<'s> {
     fn parse(input: &mut Parser<'cmt, 's>) -> PResult<Self> {
-        let first = input.parse::<MediaQuery>()?;
+        let first = match input.parse_media_query_in_list() {
+            Ok(query) => query,
+            Err(error) => match input.parse_invalid_media_query()? {
+                // don't treat an entirely missing media query list as invalid
+                MediaQuery::Invalid(TokenSeq { tokens, .. })
+                    if tokens.is_empty() && !matches!(peek!(input).token, Token::Comma(..)) =>
+                {
+                    return Err(error);
+                }
+                query => query,
+            },
+        };
         let mut span = first.span().clone();
 
         let mut queries = smallvec![first];
         while eat!(input, Comma).is_some() {
-            queries.push(input.parse()?);
+            match input.parse_media_query_in_list() {
+                Ok(query) => queries.push(query),
+                Err(..) => queries.push(input.parse_invalid_media_query()?),
+            }
         }
 
         span.end = queries.last_of_non_empty().span().end;
@@ -254,6 +273,134 @@ impl<'cmt, 's: 'cmt> Parse<'cmt, 's> for MediaQueryWithType<'s> {
 }
 
 impl<'cmt, 's: 'cmt> Parser<'cmt, 's> {
+    // https://drafts.csswg.org/mediaqueries-4/#mf-deprecated
+    fn check_deprecated_media_feature(&mut self, feature: &MediaFeature<'s>) {
+        let name = match feature {
+            MediaFeature::Plain(MediaFeaturePlain {
+                name: MediaFeatureName::Ident(name),
+                ..
+            })
+            | MediaFeature::Boolean(MediaFeatureBoolean {
+                name: MediaFeatureName::Ident(name),
+                ..
+            })
+            | MediaFeature::RangeInterval(MediaFeatureRangeInterval {
+                name: MediaFeatureName::Ident(name),
+                ..
+            })
+            | MediaFeature::Range(MediaFeatureRange {
+                left: ComponentValue::InterpolableIdent(name),
+                ..
+            })
+            | MediaFeature::Range(MediaFeatureRange {
+                right: ComponentValue::InterpolableIdent(name),
+                ..
+            }) => name,
+            _ => return,
+        };
+        if let InterpolableIdent::Literal(Ident { name, span, .. }) = name {
+            let name = name.to_ascii_lowercase();
+            let name = name
+                .strip_prefix("min-")
+                .or_else(|| name.strip_prefix("max-"))
+                .unwrap_or(&name);
+            if matches!(
+                name,
+                "device-width" | "device-height" | "device-aspect-ratio"
+            ) {
+                self.recoverable_errors.push(Error {
+                    kind: ErrorKind::DeprecatedMediaFeature,
+                    span: span.clone(),
+                });
+            }
+        }
+    }
+
+    /// Sass allows interpolation as operand of `and` and `or`.
+    fn parse_media_in_parens_or_sass_interpolation(&mut self) -> PResult<MediaInParens<'s>> {
+        if let Token::HashLBrace(..) = &peek!(self).token {
+            if let InterpolableIdent::SassInterpolated(interpolation) =
+                self.parse_sass_interpolated_ident()?
+            {
+                return Ok(MediaInParens::SassInterpolated(interpolation));
+            }
+        }
+        self.parse()
+    }
+
+    /// Skip tokens of an invalid media query until reaching `,` or end of media query list.
+    /// The skipped tokens will be kept, and the query is treated as `not all`.
+    fn parse_invalid_media_query(&mut self) -> PResult<MediaQuery<'s>> {
+        let start = peek!(self).span.start;
+        let mut tokens = vec![];
+        let mut pairs = Vec::with_capacity(1);
+        loop {
+            match &peek!(self).token {
+                Token::LParen(..) => {
+                    pairs.push(PairedToken::Paren);
+                }
+                Token::RParen(..) => {
+                    if let Some(PairedToken::Paren) = pairs.pop() {
+                    } else {
+                        break;
+                    }
+                }
+                Token::LBracket(..) => {
+                    pairs.push(PairedToken::Bracket);
+                }
+                Token::RBracket(..) => {
+                    if let Some(PairedToken::Bracket) = pairs.pop() {
+                    } else {
+                        break;
+                    }
+                }
+                Token::HashLBrace(..) => {
+                    pairs.push(PairedToken::Brace);
+                }
+                Token::LBrace(..) if !pairs.is_empty() => {
+                    pairs.push(PairedToken::Brace);
+                }
+                Token::RBrace(..) => {
+                    if let Some(PairedToken::Brace) = pairs.pop() {
+                    } else {
+                        break;
+                    }
+                }
+                Token::Comma(..)
+                | Token::LBrace(..)
+                | Token::Semicolon(..)
+                | Token::Indent(..)
+                | Token::Dedent(..)
+                | Token::Linebreak(..)
+                    if pairs.is_empty() =>
+                {
+                    break;
+                }
+                Token::Eof(..) => break,
+                _ => {}
+            }
+            tokens.push(bump!(self));
+        }
+
+        let span = if let Some((first, last)) = tokens.first().zip(tokens.last()) {
+            Span {
+                start: first.span.start,
+                end: last.span.end,
+            }
+        } else {
+            Span { start, end: start }
+        };
+        let error = Error {
+            kind: ErrorKind::InvalidMediaQuery,
+            span: span.clone(),
+        };
+        if self.options.strict_media_query_list {
+            return Err(error);
+        }
+        self.recoverable_errors.push(error);
+        Ok(MediaQuery::Invalid(TokenSeq { tokens, span }))
+    }
+
     fn parse_media_condition(&mut self, allow_or: bool) -> PResult<MediaCondition<'s>> {
         match &peek!(self).token {
             Token::Ident(ident) if ident.name().eq_ignore_ascii_case("not") => {
@@ -265,7 +412,13 @@ impl<'cmt, 's: 'cmt> Parser<'cmt, 's> {
                 })
             }
             _ => {
-                let first = self.parse::<MediaInParens>()?;
+                // condition without `or` only comes after `and` following media type,
+                // where Sass interpolation is allowed
+                let first = if allow_or {
+                    self.parse::<MediaInParens>()?
+                } else {
+                    self.parse_media_in_parens_or_sass_interpolation()?
+                };
                 let mut span = first.span().clone();
                 let mut conditions = vec![MediaConditionKind::MediaInParens(first)];
                 if let Token::Ident(ident) = &peek!(self).token {
@@ -328,12 +481,13 @@ impl<'cmt, 's: 'cmt> Parser<'cmt, 's> {
                 | Token::GreaterThan(..)
                 | Token::GreaterThanEqual(..)
                 | Token::Equal(..) => {
-                    let right_comparison = self.parse()?;
+                    let right_comparison = self.parse::<MediaFeatureComparison>()?;
                     let right = self.parse_media_feature_value()?;
                     let span = Span {
                         start: left.span().start,
                         end: right.span().end,
                     };
+                    self.check_media_feature_range_interval(&comparison, &right_comparison);
                     Ok(MediaFeature::RangeInterval(MediaFeatureRangeInterval {
                         left,
                         left_comparison: comparison,
@@ -378,6 +532,51 @@ impl<'cmt, 's: 'cmt> Parser<'cmt, 's> {
         }
     }
 
+    /// Range interval like `600px < width < 900px` requires both comparisons
+    /// to be in the same direction.
+    fn check_media_feature_range_interval(
+        &mut self,
+        left_comparison: &MediaFeatureComparison,
+        right_comparison: &MediaFeatureComparison,
+    ) {
+        use MediaFeatureComparisonKind::*;
+        match (&left_comparison.kind, &right_comparison.kind) {
+            (LessThan | LessThanOrEqual, LessThan | LessThanOrEqual)
+            | (GreaterThan | GreaterThanOrEqual, GreaterThan | GreaterThanOrEqual) => {}
+            _ => self.recoverable_errors.push(Error {
+                kind: ErrorKind::InconsistentMediaFeatureRange,
+                span: Span {
+                    start: left_comparison.span.start,
+                    end: right_comparison.span.end,
+                },
+            }),
+        }
+    }
+
+    fn parse_media_query_in_list(&mut self) -> PResult<MediaQuery<'s>> {
+        self.try_parse(|parser| {
+            let query = parser.parse::<MediaQuery>()?;
+            match peek!(parser) {
+                TokenWithSpan {
+                    token:
+                        Token::Comma(..)
+                        | Token::LBrace(..)
+                        | Token::Semicolon(..)
+                        | Token::RParen(..)
+                        | Token::Indent(..)
+                        | Token::Dedent(..)
+                        | Token::Linebreak(..)
+                        | Token::Eof(..),
+                    ..
+                } => Ok(query),
+                TokenWithSpan { span, .. } => Err(Error {
+                    kind: ErrorKind::InvalidMediaQuery,
+                    span: span.clone(),
+                }),
+            }
+        })
+    }
+
     fn parse_media_feature_value(&mut self) -> PResult<ComponentValue<'s>> {
         match self.parse_component_value_atom()? {
             ComponentValue::Number(number) => match &peek!(self).token {
@@ -386,7 +585,103 @@ impl<'cmt, 's: 'cmt> Parser<'cmt, 's> {
                 }
                 _ => Ok(ComponentValue::Number(number)),
             },
+            // feature names and keyword values like `(tan)` aren't colors
+            ComponentValue::ColorKeyword(ColorKeyword { name, .. }) => Ok(
+                ComponentValue::InterpolableIdent(InterpolableIdent::Literal(name)),
+            ),
             value => Ok(value),
         }
     }
 }
+
+pub(super) fn check_media_query_list_values(media_query_list: &MediaQueryList) -> PResult<()> {
+    media_query_list
+        .queries
+        .iter()
+        .try_for_each(|query| match query {
+            MediaQuery::ConditionOnly(condition) => check_media_condition_values(condition),
+            MediaQuery::WithType(MediaQueryWithType {
+                condition: Some(condition),
+                ..
+            }) => check_media_condition_values(condition),
+            _ => Ok(()),
+        })
+}
+
+fn check_media_condition_values(condition: &MediaCondition) -> PResult<()> {
+    condition.conditions.iter().try_for_each(|kind| match kind {
+        MediaConditionKind::MediaInParens(media_in_parens)
+        | MediaConditionKind::And(MediaAnd {
+            media_in_parens, ..
+        })
+        | MediaConditionKind::Or(MediaOr {
+            media_in_parens, ..
+        })
+        | MediaConditionKind::Not(MediaNot {
+            media_in_parens, ..
+        }) => match media_in_parens {
+            MediaInParens::MediaCondition(condition) => check_media_condition_values(condition),
+            MediaInParens::MediaFeature(feature) => check_discrete_media_feature(feature),
+            MediaInParens::SassInterpolated(..) => Ok(()),
+        },
+    })
+}
+
+// https://drafts.csswg.org/mediaqueries-5/#mq-features
+fn check_discrete_media_feature(feature: &MediaFeature) -> PResult<()> {
+    let (name, value) = match feature {
+        MediaFeature::Plain(MediaFeaturePlain {
+            name: MediaFeatureName::Ident(InterpolableIdent::Literal(name)),
+            value,
+            ..
+        }) => (name, value),
+        _ => return Ok(()),
+    };
+    let keywords: &[&str] = match &*name.name.to_ascii_lowercase() {
+        "orientation" => &["portrait", "landscape"],
+        "scan" => &["interlace", "progressive"],
+        "update" => &["none", "slow", "fast"],
+        "overflow-block" => &["none", "scroll", "paged"],
+        "overflow-inline" => &["none", "scroll"],
+        "color-gamut" => &["srgb", "p3", "rec2020"],
+        "dynamic-range" | "video-dynamic-range" => &["standard", "high"],
+        "pointer" | "any-pointer" => &["none", "coarse", "fine"],
+        "hover" | "any-hover" => &["none", "hover"],
+        "display-mode" => &[
+            "fullscreen",
+            "standalone",
+            "minimal-ui",
+            "browser",
+            "picture-in-picture",
+        ],
+        "scripting" => &["none", "initial-only", "enabled"],
+        "forced-colors" => &["none", "active"],
+        "inverted-colors" => &["none", "inverted"],
+        "prefers-color-scheme" => &["light", "dark"],
+        "prefers-contrast" => &["no-preference", "less", "more", "custom"],
+        "prefers-reduced-motion" | "prefers-reduced-transparency" | "prefers-reduced-data" => {
+            &["no-preference", "reduce"]
+        }
+        _ => return Ok(()),
+    };
+    let is_valid = match value {
+        ComponentValue::InterpolableIdent(InterpolableIdent::Literal(ident)) => keywords
+            .iter()
+            .any(|keyword| ident.name.eq_ignore_ascii_case(keyword)),
+        ComponentValue::Number(..)
+        | ComponentValue::Dimension(..)
+        | ComponentValue::Percentage(..)
+        | ComponentValue::Ratio(..)
+        | ComponentValue::InterpolableStr(..) => false,
+        // values like Sass variables can't be checked statically
+        _ => true,
+    };
+    if is_valid {
+        Ok(())
+    } else {
+        Err(Error {
+            kind: ErrorKind::InvalidMediaFeatureValue,
+            span: value.span().clone(),
+        })
+    }
+}
@@ -1,11 +1,11 @@
-use super::Parser;
+use super::{state::ParserState, Parser};
 use crate::{
     ast::*,
     bump,
-    error::PResult,
+    error::{Error, ErrorKind, PResult},
     expect, peek,
     pos::{Span, Spanned},
-    tokenizer::Token,
+    tokenizer::{Token, TokenWithSpan},
     Parse,
 };
 
@@ -14,6 +14,7 @@ mod container;
 mod counter_style;
 mod custom_media;
 mod document;
+mod font_face;
 mod font_feature_values;
 mod import;
 mod keyframes;
@@ -21,6 +22,7 @@ mod layer;
 mod media;
 mod namespace;
 mod page;
+mod property;
 mod supports;
 
 impl<'cmt, 's: 'cmt> Parse<'cmt, 's> for AtRule<'s> {
@@ -28,11 +30,35 @@ impl<'cmt, 's: 'cmt> Parse<'cmt, 's> for AtRule<'s> {
         let (at_keyword, at_keyword_span) = expect!(input, AtKeyword);
 
         let at_rule_name = at_keyword.ident.name();
-        let (prelude, block, end) = if at_rule_name.eq_ignore_ascii_case("media") {
-            let prelude = input
-                .try_parse(MediaQueryList::parse)
+        // leading BOM is allowed before `@charset`
+        let charset = if matches!(
+            input.source.get(..at_keyword_span.start),
+            Some("" | "\u{feff}")
+        ) && at_keyword.ident.raw == "charset"
+        {
+            input
+                .try_parse(|parser| parser.parse_charset(at_keyword_span.start))
                 .ok()
-                .map(AtRulePrelude::Media);
+        } else {
+            None
+        };
+        let (prelude, block, end) = if at_rule_name.eq_ignore_ascii_case("media") {
+            let prelude = match input.try_parse(MediaQueryList::parse) {
+                Ok(media_query_list) => Some(media_query_list),
+                Err(error)
+                    if input.options.strict_media_query_list
+                        && !matches!(peek!(input).token, Token::LBrace(..)) =>
+                {
+                    return Err(error);
+                }
+                Err(..) => None,
+            };
+            if let Some(media_query_list) = &prelude {
+                if input.options.strict_media_feature_value {
+                    media::check_media_query_list_values(media_query_list)?;
+                }
+            }
+            let prelude = prelude.map(AtRulePrelude::Media);
             let block = input.parse::<SimpleBlock>()?;
             let end = block.span.end;
             (prelude, Some(block), end)
@@ -49,25 +75,34 @@ impl<'cmt, 's: 'cmt> Parse<'cmt, 's> for AtRule<'s> {
             let prelude = input.parse::<ImportPrelude>()?;
             let end = prelude.span.end;
             (Some(AtRulePrelude::Import(Box::new(prelude))), None, end)
-        } else if at_rule_name.eq_ignore_ascii_case("charset") {
-            // https://drafts.csswg.org/css2/#charset%E2%91%A0
-            let prelude = input.parse::<Str>()?;
-            let end = prelude.span.end;
-            (Some(AtRulePrelude::Charset(prelude)), None, end)
+        } else if let Some(charset) = charset {
+            let end = charset.span.end;
+            (Some(AtRulePrelude::Charset(charset)), None, end)
         } else if at_rule_name.eq_ignore_ascii_case("font-face") {
-            let block = input.parse::<SimpleBlock>()?;
+            let block = input
+                .with_state(ParserState {
+                    in_font_face: true,
+                    ..input.state.clone()
+                })
+                .parse::<SimpleBlock>()?;
             let end = block.span.end;
             (None, Some(block), end)
         } else if at_rule_name.eq_ignore_ascii_case("supports") {
-            let prelude = Some(AtRulePrelude::Supports(input.parse()?));
+            let prelude = Some(AtRulePrelude::Supports(input.parse_supports_prelude()?));
             let block = input.parse::<SimpleBlock>()?;
             let end = block.span.end;
             (prelude, Some(block), end)
         } else if at_rule_name.eq_ignore_ascii_case("layer") {
-            let prelude = input
-                .try_parse(LayerName::parse)
-                .map(AtRulePrelude::Layer)
-                .ok();
+            let prelude = match input.try_parse(LayerName::parse) {
+                Ok(layer_name) => Some(AtRulePrelude::Layer(layer_name)),
+                Err(
+                    error @ Error {
+                        kind: ErrorKind::CSSWideKeywordDisallowed,
+                        ..
+                    },
+                ) => return Err(error),
+                Err(..) => None,
+            };
             let block = input.parse::<SimpleBlock>()?;
             let end = block.span.end;
             (prelude, Some(block), end)
@@ -77,10 +112,16 @@ impl<'cmt, 's: 'cmt> Parse<'cmt, 's> for AtRule<'s> {
             let end = block.span.end;
             (prelude, Some(block), end)
         } else if at_rule_name.eq_ignore_ascii_case("page") {
-            let prelude = input
-                .try_parse(PageSelectorList::parse)
-                .map(AtRulePrelude::Page)
-                .ok();
+            let prelude = match input.try_parse(PageSelectorList::parse) {
+                Ok(selectors) => Some(AtRulePrelude::Page(selectors)),
+                Err(
+                    error @ Error {
+                        kind: ErrorKind::UnexpectedWhitespace,
+                        ..
+                    },
+                ) => return Err(error),
+                Err(..) => None,
+            };
             let block = input.try_parse(SimpleBlock::parse).ok();
             let end = block
                 .as_ref()
@@ -127,6 +168,17 @@ impl<'cmt, 's: 'cmt> Parse<'cmt, 's> for AtRule<'s> {
             let block = input.parse::<SimpleBlock>()?;
             let end = block.span.end;
             (prelude, Some(block), end)
+        } else if at_rule_name.eq_ignore_ascii_case("position-try") {
+            // https://drafts.csswg.org/css-anchor-position-1/#fallback-rule
+            let prelude = Some(AtRulePrelude::PositionTry(input.parse_dashed_ident()?));
+            let block = input.parse::<SimpleBlock>()?;
+            let end = block.span.end;
+            (prelude, Some(block), end)
+        } else if at_rule_name.eq_ignore_ascii_case("starting-style") {
+            // https://drafts.csswg.org/css-transitions-2/#defining-before-change-style
+            let block = input.parse::<SimpleBlock>()?;
+            let end = block.span.end;
+            (None, Some(block), end)
         } else if at_rule_name.eq_ignore_ascii_case("nest") {
             // https://www.w3.org/TR/css-nesting-1/#at-nest
             let prelude = Some(AtRulePrelude::Nest(input.parse()?));
@@ -137,6 +189,9 @@ impl<'cmt, 's: 'cmt> Parse<'cmt, 's> for AtRule<'s> {
             // https://drafts.css-houdini.org/css-properties-values-api/#at-property-rule
             let prelude = Some(AtRulePrelude::Property(input.parse_dashed_ident()?));
             let block = input.parse::<SimpleBlock>()?;
+            if input.options.strict_property_rule {
+                input.validate_property_rule(&block)?;
+            }
             let end = block.span.end;
             (prelude, Some(block), end)
         } else if at_rule_name.eq_ignore_ascii_case("document")
@@ -191,6 +246,16 @@ impl<'cmt, 's: 'cmt> Parse<'cmt, 's> for AtRule<'s> {
             (prelude, block, end)
         };
 
+        let raw_prelude = if input.options.preserve_raw {
+            let raw_end = match &block {
+                Some(block) => block.span.start,
+                None => peek!(input).span.start,
+            };
+            input.source.get(at_keyword_span.end..raw_end)
+        } else {
+            None
+        };
+
         let span = Span {
             start: at_keyword_span.start,
             end,
@@ -207,6 +272,7 @@ impl<'cmt, 's: 'cmt> Parse<'cmt, 's> for AtRule<'s> {
                 },
             },
             prelude,
+            raw_prelude,
             block,
             span,
         })
@@ -214,6 +280,28 @@ impl<'cmt, 's: 'cmt> Parse<'cmt, 's> for AtRule<'s> {
 }
 
 impl<'cmt, 's: 'cmt> Parser<'cmt, 's> {
+    // https://drafts.csswg.org/css2/#charset%E2%91%A0
+    /// Only byte-exact `@charset "...";` at the very beginning is a real charset.
+    /// Otherwise, it's treated as an unknown at-rule which happens to be named `charset`.
+    fn parse_charset(&mut self, start: usize) -> PResult<Str<'s>> {
+        let charset = self.parse::<Str>()?;
+        match peek!(self) {
+            TokenWithSpan {
+                token: Token::Semicolon(..),
+                span,
+            } if charset.span.start == start + "@charset ".len()
+                && charset.raw.starts_with('"')
+                && span.start == charset.span.end =>
+            {
+                Ok(charset)
+            }
+            TokenWithSpan { span, .. } => Err(Error {
+                kind: ErrorKind::TryParseError,
+                span: span.clone(),
+            }),
+        }
+    }
+
     fn parse_unknown_at_rule_prelude(&mut self) -> PResult<Option<TokenSeq<'s>>> {
         let mut tokens = vec![];
         loop {
@@ -2,7 +2,7 @@ use super::Parser;
 use crate::{
     ast::*,
     eat,
-    error::PResult,
+    error::{Error, ErrorKind, PResult},
     expect, peek,
     pos::{Span, Spanned},
     tokenizer::{Token, TokenWithSpan},
@@ -40,6 +40,18 @@ impl<'cmt, 's: 'cmt> Parse<'cmt, 's> for PageSelector<'s> {
                     end = item.span.end;
                     pseudo.push(item);
                 }
+                TokenWithSpan {
+                    token: Token::Colon(..),
+                    span,
+                } => {
+                    return Err(Error {
+                        kind: ErrorKind::UnexpectedWhitespace,
+                        span: Span {
+                            start: end,
+                            end: span.start,
+                        },
+                    });
+                }
                 _ => break,
             }
         }
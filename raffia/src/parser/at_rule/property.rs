@@ -0,0 +1,74 @@
+use super::Parser;
+use crate::{
+    ast::*,
+    error::{Error, ErrorKind, PResult},
+    pos::Spanned,
+};
+
+impl<'cmt, 's: 'cmt> Parser<'cmt, 's> {
+    // https://drafts.css-houdini.org/css-properties-values-api/#the-css-property-rule-interface
+    pub(super) fn validate_property_rule(&self, block: &SimpleBlock<'s>) -> PResult<()> {
+        let mut syntax = None;
+        let mut has_inherits = false;
+        let mut has_initial_value = false;
+        for (decl, name) in block.statements.iter().filter_map(|stmt| match stmt {
+            Statement::Declaration(
+                decl @ Declaration {
+                    name: InterpolableIdent::Literal(name),
+                    ..
+                },
+            ) => Some((decl, name)),
+            _ => None,
+        }) {
+            if name.name.eq_ignore_ascii_case("syntax") {
+                syntax = Some(decl);
+            } else if name.name.eq_ignore_ascii_case("initial-value") {
+                has_initial_value = true;
+            } else if name.name.eq_ignore_ascii_case("inherits") {
+                has_inherits = true;
+                match &decl.value[..] {
+                    [ComponentValue::InterpolableIdent(InterpolableIdent::Literal(ident))]
+                        if ident.name.eq_ignore_ascii_case("true")
+                            || ident.name.eq_ignore_ascii_case("false") => {}
+                    _ => {
+                        return Err(Error {
+                            kind: ErrorKind::InvalidPropertyInherits,
+                            span: decl.span.clone(),
+                        });
+                    }
+                }
+            }
+        }
+
+        let syntax = match syntax {
+            Some(syntax) => syntax,
+            None => {
+                return Err(Error {
+                    kind: ErrorKind::MissingPropertyDescriptor("syntax"),
+                    span: block.span.clone(),
+                });
+            }
+        };
+        if !has_inherits {
+            return Err(Error {
+                kind: ErrorKind::MissingPropertyDescriptor("inherits"),
+                span: block.span.clone(),
+            });
+        }
+
+        if has_initial_value {
+            return Ok(());
+        }
+        match &syntax.value[..] {
+            [ComponentValue::InterpolableStr(InterpolableStr::Literal(str))]
+                if str.value.trim() == "*" =>
+            {
+                Ok(())
+            }
+            _ => Err(Error {
+                kind: ErrorKind::MissingPropertyInitialValue,
+                span: syntax.span().clone(),
+            }),
+        }
+    }
+}
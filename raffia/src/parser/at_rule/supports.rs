@@ -30,42 +30,7 @@ impl<'cmt, 's: 'cmt> Parse<'cmt, 's> for SupportsCondition<'s> {
             }
             _ => {
                 let first = input.parse::<SupportsInParens>()?;
-                let mut span = first.span().clone();
-                let mut conditions = vec![SupportsConditionKind::SupportsInParens(first)];
-                while let Token::Ident(ident) = &peek!(input).token {
-                    let name = ident.name();
-                    if name.eq_ignore_ascii_case("and") {
-                        let ident = input.parse::<Ident>()?;
-                        let condition = input.parse::<SupportsInParens>()?;
-                        let span = Span {
-                            start: ident.span.start,
-                            end: condition.span().end,
-                        };
-                        conditions.push(SupportsConditionKind::And(SupportsAnd {
-                            keyword: ident,
-                            condition,
-                            span,
-                        }));
-                    } else if name.eq_ignore_ascii_case("or") {
-                        let ident = input.parse::<Ident>()?;
-                        let condition = input.parse::<SupportsInParens>()?;
-                        let span = Span {
-                            start: ident.span.start,
-                            end: condition.span().end,
-                        };
-                        conditions.push(SupportsConditionKind::Or(SupportsOr {
-                            keyword: ident,
-                            condition,
-                            span,
-                        }));
-                    } else {
-                        break;
-                    }
-                }
-                if let Some(last) = conditions.last() {
-                    span.end = last.span().end;
-                }
-                Ok(SupportsCondition { conditions, span })
+                input.parse_supports_and_or(first)
             }
         }
     }
@@ -108,3 +73,113 @@ impl<'cmt, 's: 'cmt> Parse<'cmt, 's> for SupportsDecl<'s> {
         })
     }
 }
+
+impl<'cmt, 's: 'cmt> Parser<'cmt, 's> {
+    pub(super) fn parse_supports_prelude(&mut self) -> PResult<SupportsCondition<'s>> {
+        if self.options.allow_bare_supports_declaration {
+            if let Ok(condition) = self.try_parse(Parser::parse_bare_supports_decl) {
+                return Ok(condition);
+            }
+        }
+        self.parse()
+    }
+
+    /// Parse the rest conditions combined by `and` or `or` after the first one.
+    fn parse_supports_and_or(
+        &mut self,
+        first: SupportsInParens<'s>,
+    ) -> PResult<SupportsCondition<'s>> {
+        let mut span = first.span().clone();
+        let mut conditions = vec![SupportsConditionKind::SupportsInParens(first)];
+        // `and` and `or` can't be mixed at the same level without parens
+        let mut keyword = None;
+        while let Token::Ident(ident) = &peek!(self).token {
+            let name = ident.name();
+            if name.eq_ignore_ascii_case("and") && keyword != Some("or") {
+                keyword = Some("and");
+                let ident = self.parse::<Ident>()?;
+                let condition = self.parse::<SupportsInParens>()?;
+                let span = Span {
+                    start: ident.span.start,
+                    end: condition.span().end,
+                };
+                conditions.push(SupportsConditionKind::And(SupportsAnd {
+                    keyword: ident,
+                    condition,
+                    span,
+                }));
+            } else if name.eq_ignore_ascii_case("or") && keyword != Some("and") {
+                keyword = Some("or");
+                let ident = self.parse::<Ident>()?;
+                let condition = self.parse::<SupportsInParens>()?;
+                let span = Span {
+                    start: ident.span.start,
+                    end: condition.span().end,
+                };
+                conditions.push(SupportsConditionKind::Or(SupportsOr {
+                    keyword: ident,
+                    condition,
+                    span,
+                }));
+            } else if name.eq_ignore_ascii_case("and") || name.eq_ignore_ascii_case("or") {
+                return Err(Error {
+                    kind: ErrorKind::MixedSupportsConditionOperators,
+                    span: peek!(self).span.clone(),
+                });
+            } else {
+                break;
+            }
+        }
+        if let Some(last) = conditions.last() {
+            span.end = last.span().end;
+        }
+        Ok(SupportsCondition { conditions, span })
+    }
+
+    /// Parse declaration without parens, such as `@supports display: grid {}`.
+    /// This isn't allowed by spec, but accepted by some parsers.
+    fn parse_bare_supports_decl(&mut self) -> PResult<SupportsCondition<'s>> {
+        let name = self.parse::<InterpolableIdent>()?;
+        let colon_span = expect!(self, Colon).1;
+
+        let mut value = Vec::with_capacity(1);
+        loop {
+            match &peek!(self).token {
+                Token::LBrace(..) | Token::Semicolon(..) | Token::Indent(..) | Token::Eof(..) => {
+                    break
+                }
+                Token::Ident(ident)
+                    if ident.name().eq_ignore_ascii_case("and")
+                        || ident.name().eq_ignore_ascii_case("or") =>
+                {
+                    break
+                }
+                _ => value.push(self.parse::<ComponentValue>()?),
+            }
+        }
+        let end = if let Some(last) = value.last() {
+            last.span().end
+        } else {
+            return Err(Error {
+                kind: ErrorKind::ExpectComponentValue,
+                span: colon_span,
+            });
+        };
+
+        let span = Span {
+            start: name.span().start,
+            end,
+        };
+        let decl = Declaration {
+            name,
+            value,
+            important: None,
+            less_property_merge: None,
+            span: span.clone(),
+        };
+        self.parse_supports_and_or(SupportsInParens::Feature(Box::new(SupportsDecl {
+            decl,
+            span,
+        })))
+    }
+}
@@ -1,5 +1,6 @@
 use super::Parser;
 use crate::{
+    config::{IndentUnit, ParserOptions},
     tokenizer::{token::Comment, Tokenizer},
     Syntax,
 };
@@ -13,6 +14,7 @@ use crate::{
 pub struct ParserBuilder<'cmt, 's: 'cmt> {
     source: &'s str,
     syntax: Syntax,
+    options: ParserOptions,
     comments: Option<&'cmt mut Vec<Comment<'s>>>,
 }
 
@@ -22,6 +24,7 @@ impl<'cmt, 's: 'cmt> ParserBuilder<'cmt, 's> {
         ParserBuilder {
             source,
             syntax: Syntax::default(),
+            options: Default::default(),
             comments: None,
         }
     }
@@ -47,15 +50,127 @@ impl<'cmt, 's: 'cmt> ParserBuilder<'cmt, 's> {
         self
     }
 
+    /// Report an error and stop parsing when there's an invalid media query
+    /// in media query list, instead of recovering it as [`MediaQuery::Invalid`](crate::ast::MediaQuery::Invalid).
+    pub fn strict_media_query_list(mut self, strict: bool) -> Self {
+        self.options.strict_media_query_list = strict;
+        self
+    }
+
+    /// Report an error and stop parsing when declaration value is empty
+    /// or has values after `!important`, instead of recording a recoverable error.
+    pub fn strict_declaration_value(mut self, strict: bool) -> Self {
+        self.options.strict_declaration_value = strict;
+        self
+    }
+
+    /// Report an error and stop parsing when CSS-wide keyword is mixed with other values
+    /// in declaration, such as `color: revert-layer red;`.
+    pub fn strict_css_wide_keyword(mut self, strict: bool) -> Self {
+        self.options.strict_css_wide_keyword = strict;
+        self
+    }
+
+    /// Report an error and stop parsing when keyframes name is a reserved keyword,
+    /// instead of recording a recoverable error.
+    pub fn strict_keyframes_name(mut self, strict: bool) -> Self {
+        self.options.strict_keyframes_name = strict;
+        self
+    }
+
+    /// Report an error and stop parsing when layer name contains a CSS-wide keyword,
+    /// instead of recording a recoverable error.
+    pub fn strict_layer_name(mut self, strict: bool) -> Self {
+        self.options.strict_layer_name = strict;
+        self
+    }
+
+    /// Only accept a single simple selector as argument of `:not()`, as Selectors Level 3 requires.
+    pub fn strict_not_pseudo_class(mut self, strict: bool) -> Self {
+        self.options.strict_not_pseudo_class = strict;
+        self
+    }
+
+    /// Report an error and stop parsing when `@property` rule has missing or invalid descriptors.
+    pub fn strict_property_rule(mut self, strict: bool) -> Self {
+        self.options.strict_property_rule = strict;
+        self
+    }
+
+    /// Report an error and stop parsing when discrete media feature such as `scan`
+    /// receives a keyword which isn't allowed for it.
+    pub fn strict_media_feature_value(mut self, strict: bool) -> Self {
+        self.options.strict_media_feature_value = strict;
+        self
+    }
+
+    /// Report an error and stop parsing when block comment isn't closed before end of file.
+    pub fn strict_block_comment(mut self, strict: bool) -> Self {
+        self.options.strict_block_comment = strict;
+        self
+    }
+
+    /// Report an error and stop parsing when `font-display` descriptor in `@font-face` is invalid.
+    pub fn strict_font_face_descriptor(mut self, strict: bool) -> Self {
+        self.options.strict_font_face_descriptor = strict;
+        self
+    }
+
+    /// Keep raw source text of at-rule prelude as `raw_prelude` in [`AtRule`](crate::ast::AtRule).
+    pub fn preserve_raw(mut self, preserve: bool) -> Self {
+        self.options.preserve_raw = preserve;
+        self
+    }
+
+    /// Record a recoverable error for deprecated media features, such as `device-width`.
+    pub fn warn_deprecated_media_features(mut self, warn: bool) -> Self {
+        self.options.warn_deprecated_media_features = warn;
+        self
+    }
+
+    /// Accept declaration without parens as condition of `@supports`, such as `@supports display: grid {}`.
+    pub fn allow_bare_supports_declaration(mut self, allow: bool) -> Self {
+        self.options.allow_bare_supports_declaration = allow;
+        self
+    }
+
+    /// Record a recoverable error instead of a fatal error for string which is unterminated before line break.
+    pub fn allow_bad_string(mut self, allow: bool) -> Self {
+        self.options.allow_bad_string = allow;
+        self
+    }
+
+    /// Record a recoverable error instead of a fatal error for invalid escape, such as a backslash at end of file.
+    pub fn allow_invalid_escape(mut self, allow: bool) -> Self {
+        self.options.allow_invalid_escape = allow;
+        self
+    }
+
+    /// Report an error and stop parsing when indentation in indented Sass syntax isn't a multiple of the given unit.
+    pub fn sass_indent_unit(mut self, unit: IndentUnit) -> Self {
+        self.options.sass_indent_unit = Some(unit);
+        self
+    }
+
+    /// Parse Tailwind CSS `theme()` function into [`ThemeFunction`](crate::ast::ThemeFunction) instead of generic function.
+    pub fn tailwind(mut self, enabled: bool) -> Self {
+        self.options.tailwind = enabled;
+        self
+    }
+
     /// Build a parser.
     pub fn build(self) -> Parser<'cmt, 's> {
+        let mut tokenizer = Tokenizer::new(self.source, self.syntax.clone(), self.comments);
+        tokenizer.indent_unit = self.options.sass_indent_unit;
+        tokenizer.strict_block_comment = self.options.strict_block_comment;
+        tokenizer.allow_invalid_escape = self.options.allow_invalid_escape;
         Parser {
             source: self.source,
-            syntax: self.syntax.clone(),
-            tokenizer: Tokenizer::new(self.source, self.syntax, self.comments),
+            syntax: self.syntax,
+            options: self.options,
+            tokenizer,
             state: Default::default(),
             recoverable_errors: vec![],
-            cached_token: None,
         }
     }
 }
@@ -1,16 +1,327 @@
-use super::Parser;
+use super::{
+    state::{ParserState, QualifiedRuleContext},
+    Parser,
+};
 use crate::{
     ast::*,
     bump,
     config::Syntax,
-    error::PResult,
+    eat,
+    error::{Error, ErrorKind, PResult},
     expect, expect_without_ws_or_comments, peek,
     pos::{Span, Spanned},
     tokenizer::{Token, TokenWithSpan},
+    util::LastOfNonEmpty,
     Parse,
 };
 
+const PRECEDENCE_MULTIPLY: u8 = 2;
+const PRECEDENCE_PLUS: u8 = 1;
+
 impl<'cmt, 's: 'cmt> Parser<'cmt, 's> {
+    fn parse_less_condition(&mut self) -> PResult<LessCondition<'s>> {
+        // `and` has higher precedence than `or`
+        let mut left = self.parse_less_condition_and()?;
+        while let Some(op) =
+            self.parse_less_logical_operator("or", LessBinaryConditionOperatorKind::Or)?
+        {
+            let right = self.parse_less_condition_and()?;
+            let span = Span {
+                start: left.span().start,
+                end: right.span().end,
+            };
+            left = LessCondition::Binary(LessBinaryCondition {
+                left: Box::new(left),
+                op,
+                right: Box::new(right),
+                span,
+            });
+        }
+        Ok(left)
+    }
+
+    fn parse_less_condition_and(&mut self) -> PResult<LessCondition<'s>> {
+        let mut left = self.parse_less_condition_operand()?;
+        while let Some(op) =
+            self.parse_less_logical_operator("and", LessBinaryConditionOperatorKind::And)?
+        {
+            let right = self.parse_less_condition_operand()?;
+            let span = Span {
+                start: left.span().start,
+                end: right.span().end,
+            };
+            left = LessCondition::Binary(LessBinaryCondition {
+                left: Box::new(left),
+                op,
+                right: Box::new(right),
+                span,
+            });
+        }
+        Ok(left)
+    }
+
+    fn parse_less_condition_operand(&mut self) -> PResult<LessCondition<'s>> {
+        match &peek!(self).token {
+            Token::Ident(ident) if ident.name().eq_ignore_ascii_case("not") => {
+                let start = bump!(self).span.start;
+                let condition = self.parse_less_condition_operand()?;
+                let span = Span {
+                    start,
+                    end: condition.span().end,
+                };
+                Ok(LessCondition::Negated(LessNegatedCondition {
+                    condition: Box::new(condition),
+                    span,
+                }))
+            }
+            _ => {
+                let start = expect!(self, LParen).1.start;
+                let condition = match &peek!(self).token {
+                    Token::LParen(..) => self.parse_less_condition()?,
+                    Token::Ident(ident) if ident.name().eq_ignore_ascii_case("not") => {
+                        self.parse_less_condition()?
+                    }
+                    _ => self.parse_less_comparison()?,
+                };
+                let end = expect!(self, RParen).1.end;
+                Ok(LessCondition::Parenthesized(LessParenthesizedCondition {
+                    condition: Box::new(condition),
+                    span: Span { start, end },
+                }))
+            }
+        }
+    }
+
+    fn parse_less_comparison(&mut self) -> PResult<LessCondition<'s>> {
+        let left = self.parse::<ComponentValue>()?;
+        let op = match &peek!(self).token {
+            Token::GreaterThan(..) => LessBinaryConditionOperator {
+                kind: LessBinaryConditionOperatorKind::GreaterThan,
+                span: bump!(self).span,
+            },
+            Token::GreaterThanEqual(..) => LessBinaryConditionOperator {
+                kind: LessBinaryConditionOperatorKind::GreaterThanOrEqual,
+                span: bump!(self).span,
+            },
+            Token::LessThan(..) => LessBinaryConditionOperator {
+                kind: LessBinaryConditionOperatorKind::LessThan,
+                span: bump!(self).span,
+            },
+            Token::LessThanEqual(..) => LessBinaryConditionOperator {
+                kind: LessBinaryConditionOperatorKind::LessThanOrEqual,
+                span: bump!(self).span,
+            },
+            Token::Equal(..) => {
+                let span = bump!(self).span;
+                match peek!(self) {
+                    // Less also accepts `=<` as "less than or equal"
+                    TokenWithSpan {
+                        token: Token::LessThan(..),
+                        span: less_than_span,
+                    } if span.end == less_than_span.start => {
+                        let end = bump!(self).span.end;
+                        LessBinaryConditionOperator {
+                            kind: LessBinaryConditionOperatorKind::LessThanOrEqual,
+                            span: Span {
+                                start: span.start,
+                                end,
+                            },
+                        }
+                    }
+                    _ => LessBinaryConditionOperator {
+                        kind: LessBinaryConditionOperatorKind::Equal,
+                        span,
+                    },
+                }
+            }
+            _ => {
+                return Ok(match left {
+                    ComponentValue::Function(function) => convert_less_guard_function(function),
+                    left => LessCondition::Value(left),
+                })
+            }
+        };
+        let right = self.parse::<ComponentValue>()?;
+        let span = Span {
+            start: left.span().start,
+            end: right.span().end,
+        };
+        Ok(LessCondition::Binary(LessBinaryCondition {
+            left: Box::new(LessCondition::Value(left)),
+            op,
+            right: Box::new(LessCondition::Value(right)),
+            span,
+        }))
+    }
+
+    pub(super) fn parse_less_operation(&mut self) -> PResult<ComponentValue<'s>> {
+        debug_assert_eq!(self.syntax, Syntax::Less);
+        self.parse_less_operation_recursively(0)
+    }
+
+    fn parse_less_operation_recursively(&mut self, precedence: u8) -> PResult<ComponentValue<'s>> {
+        let mut left = if precedence >= PRECEDENCE_MULTIPLY {
+            self.parse_less_operand()?
+        } else {
+            self.parse_less_operation_recursively(precedence + 1)?
+        };
+
+        loop {
+            let operator = match &peek!(self).token {
+                Token::Solidus(..) if precedence == PRECEDENCE_MULTIPLY => {
+                    match self.try_parse(|parser| parser.parse_less_division(&left)) {
+                        Ok((operator, right)) => {
+                            let span = Span {
+                                start: left.span().start,
+                                end: right.span().end,
+                            };
+                            left = ComponentValue::LessBinaryOperation(LessBinaryOperation {
+                                left: Box::new(left),
+                                op: operator,
+                                right: Box::new(right),
+                                span,
+                            });
+                            continue;
+                        }
+                        Err(..) => break,
+                    }
+                }
+                Token::Asterisk(..) if precedence == PRECEDENCE_MULTIPLY => LessOperationOperator {
+                    kind: LessOperationOperatorKind::Multiply,
+                    span: bump!(self).span,
+                },
+                Token::Plus(..) | Token::Minus(..) if precedence == PRECEDENCE_PLUS => {
+                    if self.is_less_unary_operator(&left)? {
+                        break;
+                    }
+                    let TokenWithSpan { token, span } = bump!(self);
+                    LessOperationOperator {
+                        kind: if let Token::Plus(..) = token {
+                            LessOperationOperatorKind::Plus
+                        } else {
+                            LessOperationOperatorKind::Minus
+                        },
+                        span,
+                    }
+                }
+                _ => break,
+            };
+
+            let right = self.parse_less_operation_recursively(precedence + 1)?;
+            let span = Span {
+                start: left.span().start,
+                end: right.span().end,
+            };
+            left = ComponentValue::LessBinaryOperation(LessBinaryOperation {
+                left: Box::new(left),
+                op: operator,
+                right: Box::new(right),
+                span,
+            });
+        }
+
+        Ok(left)
+    }
+
+    /// `+` or `-` which is separated from left operand by whitespace
+    /// but attached to the following value, such as `@a -@b`,
+    /// starts another value of a list instead of being a binary operator.
+    fn is_less_unary_operator(&mut self, left: &ComponentValue<'s>) -> PResult<bool> {
+        let op_span = peek!(self).span.clone();
+        if left.span().end == op_span.start {
+            return Ok(false);
+        }
+        let is_unary = self.tokenizer.peek_nth(1)?.span.start == op_span.end;
+        self.take_tokenizer_errors();
+        Ok(is_unary)
+    }
+
+    fn parse_less_operand(&mut self) -> PResult<ComponentValue<'s>> {
+        let op = match &peek!(self).token {
+            Token::Plus(..) => LessUnaryOperator {
+                kind: LessUnaryOperatorKind::Plus,
+                span: bump!(self).span,
+            },
+            Token::Minus(..) => LessUnaryOperator {
+                kind: LessUnaryOperatorKind::Minus,
+                span: bump!(self).span,
+            },
+            _ => return self.parse_less_operand_atom(),
+        };
+
+        let expr = self.parse_less_operand_atom()?;
+        self.assert_no_ws_or_comment(&op.span, expr.span())?;
+        let span = Span {
+            start: op.span.start,
+            end: expr.span().end,
+        };
+        Ok(ComponentValue::LessUnaryOperation(LessUnaryOperation {
+            op,
+            expr: Box::new(expr),
+            span,
+        }))
+    }
+
+    fn parse_less_operand_atom(&mut self) -> PResult<ComponentValue<'s>> {
+        if let Some((_, Span { start, .. })) = eat!(self, LParen) {
+            let operation = self.parse_less_operation()?;
+            let end = expect!(self, RParen).1.end;
+            Ok(ComponentValue::LessParenthesizedOperation(
+                LessParenthesizedOperation {
+                    operation: Box::new(operation),
+                    span: Span { start, end },
+                },
+            ))
+        } else {
+            self.parse_component_value_atom().map(convert_less_function)
+        }
+    }
+
+    /// `/` is treated as division only if at least one of operands isn't number literal,
+    /// so `font: 12px/1.5` is kept as separator while `@a / 2` is division.
+    fn parse_less_division(
+        &mut self,
+        left: &ComponentValue<'s>,
+    ) -> PResult<(LessOperationOperator, ComponentValue<'s>)> {
+        let span = expect!(self, Solidus).1;
+        let right = self.parse_less_operand()?;
+
+        let is_literal = |value: &ComponentValue| {
+            matches!(
+                value,
+                ComponentValue::Number(..)
+                    | ComponentValue::Dimension(..)
+                    | ComponentValue::Percentage(..)
+            )
+        };
+        let is_numeric = |value: &ComponentValue| {
+            is_literal(value)
+                || matches!(
+                    value,
+                    ComponentValue::Function(..)
+                        | ComponentValue::LessBinaryOperation(..)
+                        | ComponentValue::LessParenthesizedOperation(..)
+                        | ComponentValue::LessUnaryOperation(..)
+                        | ComponentValue::LessVariable(..)
+                        | ComponentValue::LessVariableVariable(..)
+                )
+        };
+        if is_numeric(left) && is_numeric(&right) && !(is_literal(left) && is_literal(&right)) {
+            Ok((
+                LessOperationOperator {
+                    kind: LessOperationOperatorKind::Division,
+                    span,
+                },
+                right,
+            ))
+        } else {
+            Err(Error {
+                kind: ErrorKind::TryParseError,
+                span,
+            })
+        }
+    }
+
     pub(super) fn parse_less_interpolated_ident(&mut self) -> PResult<InterpolableIdent<'s>> {
         debug_assert_eq!(self.syntax, Syntax::Less);
 
@@ -32,7 +343,13 @@ impl<'cmt, 's: 'cmt> Parser<'cmt, 's> {
                 }
             }
             Token::AtLBraceVar(..) => self.parse().map(LessInterpolatedIdentElement::Variable)?,
-            _ => unreachable!(),
+            _ => {
+                let TokenWithSpan { token, span } = bump!(self);
+                return Err(Error {
+                    kind: ErrorKind::Unexpected("<ident>` or `@{", token.symbol()),
+                    span,
+                });
+            }
         };
         let mut span = first.span().clone();
 
@@ -67,6 +384,124 @@ impl<'cmt, 's: 'cmt> Parser<'cmt, 's> {
             span,
         }))
     }
+
+    /// Parse the rest of guarded rule after its selector, starting from `when` keyword.
+    pub(super) fn parse_less_guarded_rule(
+        &mut self,
+        selector: SelectorList<'s>,
+    ) -> PResult<LessGuardedRule<'s>> {
+        let guard = self.parse::<LessGuard>()?;
+        let block = self.parse::<SimpleBlock>()?;
+        let span = Span {
+            start: selector.span.start,
+            end: block.span.end,
+        };
+        Ok(LessGuardedRule {
+            selector,
+            guard,
+            block,
+            span,
+        })
+    }
+
+    /// Parse arguments and `!important` of mixin call after its callee.
+    pub(super) fn parse_less_mixin_call_rest(
+        &mut self,
+        callee: Vec<LessMixinCalleeSegment<'s>>,
+    ) -> PResult<LessMixinCall<'s>> {
+        let start = callee.first().map_or(0, |segment| segment.span.start);
+        let mut end = callee.last().map_or(0, |segment| segment.span.end);
+
+        let args = match peek!(self) {
+            TokenWithSpan {
+                token: Token::LParen(..),
+                span,
+            } if span.start == end => {
+                expect!(self, LParen);
+                let args = if let Token::RParen(..) = &peek!(self).token {
+                    vec![]
+                } else {
+                    self.parse_component_values(
+                        /* allow_comma */ true, /* allow_semicolon */ true,
+                    )?
+                    .values
+                };
+                end = expect!(self, RParen).1.end;
+                Some(args)
+            }
+            _ => None,
+        };
+
+        let important = if let Token::Exclamation(..) = &peek!(self).token {
+            let important = self.parse::<ImportantAnnotation>()?;
+            end = important.span.end;
+            Some(important)
+        } else {
+            None
+        };
+
+        Ok(LessMixinCall {
+            callee,
+            args,
+            important,
+            span: Span { start, end },
+        })
+    }
+
+    fn parse_less_logical_operator(
+        &mut self,
+        keyword: &'static str,
+        kind: LessBinaryConditionOperatorKind,
+    ) -> PResult<Option<LessBinaryConditionOperator>> {
+        match &peek!(self).token {
+            Token::Ident(ident) if ident.name().eq_ignore_ascii_case(keyword) => {
+                Ok(Some(LessBinaryConditionOperator {
+                    kind,
+                    span: bump!(self).span,
+                }))
+            }
+            _ => Ok(None),
+        }
+    }
+}
+
+impl<'cmt, 's: 'cmt> Parse<'cmt, 's> for LessGuard<'s> {
+    fn parse(input: &mut Parser<'cmt, 's>) -> PResult<Self> {
+        debug_assert_eq!(input.syntax, Syntax::Less);
+
+        let (keyword, keyword_span) = expect!(input, Ident);
+        if !keyword.name().eq_ignore_ascii_case("when") {
+            return Err(Error {
+                kind: ErrorKind::ExpectLessKeyword("when"),
+                span: keyword_span,
+            });
+        }
+
+        let mut conditions = vec![input.parse_less_condition()?];
+        while eat!(input, Comma).is_some() {
+            conditions.push(input.parse_less_condition()?);
+        }
+
+        let span = Span {
+            start: keyword_span.start,
+            end: conditions.last_of_non_empty().span().end,
+        };
+        Ok(LessGuard { conditions, span })
+    }
+}
+
+impl<'cmt, 's: 'cmt> Parse<'cmt, 's> for LessGuardedRule<'s> {
+    fn parse(input: &mut Parser<'cmt, 's>) -> PResult<Self> {
+        debug_assert_eq!(input.syntax, Syntax::Less);
+
+        let selector = input
+            .with_state(ParserState {
+                qualified_rule_ctx: Some(QualifiedRuleContext::Selector),
+                ..input.state
+            })
+            .parse::<SelectorList>()?;
+        input.parse_less_guarded_rule(selector)
+    }
 }
 
 impl<'cmt, 's: 'cmt> Parse<'cmt, 's> for LessInterpolatedStr<'s> {
@@ -112,6 +547,69 @@ impl<'cmt, 's: 'cmt> Parse<'cmt, 's> for LessInterpolatedStr<'s> {
     }
 }
 
+impl<'cmt, 's: 'cmt> Parse<'cmt, 's> for LessMixinCall<'s> {
+    fn parse(input: &mut Parser<'cmt, 's>) -> PResult<Self> {
+        debug_assert_eq!(input.syntax, Syntax::Less);
+
+        let first = input.parse::<LessMixinCalleeSegment>()?;
+        let mut end = first.span.end;
+        let mut callee = vec![first];
+        loop {
+            let combinator = match peek!(input) {
+                TokenWithSpan {
+                    token: Token::GreaterThan(..),
+                    ..
+                } => {
+                    let (_, span) = expect!(input, GreaterThan);
+                    Some(Combinator {
+                        kind: CombinatorKind::Child,
+                        span,
+                    })
+                }
+                TokenWithSpan {
+                    token: Token::Dot(..) | Token::Hash(..),
+                    span,
+                } if span.start > end => Some(Combinator {
+                    kind: CombinatorKind::Descendant,
+                    span: Span {
+                        start: end,
+                        end: span.start,
+                    },
+                }),
+                TokenWithSpan {
+                    token: Token::Dot(..) | Token::Hash(..),
+                    ..
+                } => None,
+                _ => break,
+            };
+            let mut segment = input.parse::<LessMixinCalleeSegment>()?;
+            if let Some(combinator) = combinator {
+                segment.span.start = combinator.span.start;
+                segment.combinator = Some(combinator);
+            }
+            end = segment.span.end;
+            callee.push(segment);
+        }
+
+        input.parse_less_mixin_call_rest(callee)
+    }
+}
+
+impl<'cmt, 's: 'cmt> Parse<'cmt, 's> for LessMixinCalleeSegment<'s> {
+    fn parse(input: &mut Parser<'cmt, 's>) -> PResult<Self> {
+        let name = match &peek!(input).token {
+            Token::Hash(..) => LessMixinName::IdSelector(input.parse()?),
+            _ => LessMixinName::ClassSelector(input.parse()?),
+        };
+        let span = name.span().clone();
+        Ok(LessMixinCalleeSegment {
+            combinator: None,
+            name,
+            span,
+        })
+    }
+}
+
 impl<'cmt, 's: 'cmt> Parse<'cmt, 's> for Option<LessPropertyMerge> {
     fn parse(input: &mut Parser<'cmt, 's>) -> PResult<Self> {
         debug_assert_eq!(input.syntax, Syntax::Less);
@@ -193,3 +691,142 @@ impl<'cmt, 's: 'cmt> Parse<'cmt, 's> for LessVariableVariable<'s> {
         Ok(LessVariableVariable { variable, span })
     }
 }
+
+fn convert_less_guard_function(function: Function<'_>) -> LessCondition<'_> {
+    match function {
+        Function {
+            name: InterpolableIdent::Literal(name),
+            args,
+            span,
+        } if args.is_empty() && name.name.eq_ignore_ascii_case("default") => {
+            LessCondition::Function(LessGuardFunction {
+                name,
+                arg: None,
+                span,
+            })
+        }
+        Function {
+            name: InterpolableIdent::Literal(name),
+            mut args,
+            span,
+        } if args.len() == 1 && is_less_type_function(&name.name) => {
+            LessCondition::Function(LessGuardFunction {
+                name,
+                arg: args.pop().map(Box::new),
+                span,
+            })
+        }
+        function => LessCondition::Value(ComponentValue::Function(function)),
+    }
+}
+
+/// Class and ID selectors chained by descendant or child combinators,
+/// such as `#ns > .mixin`, can be callee of mixin call.
+pub(super) fn is_less_mixin_callee(selector_list: &SelectorList) -> bool {
+    match &selector_list.selectors[..] {
+        [complex] => complex.children.iter().all(|child| match child {
+            ComplexSelectorChild::CompoundSelector(compound) => compound
+                .children
+                .iter()
+                .all(|simple| matches!(simple, SimpleSelector::Class(..) | SimpleSelector::Id(..))),
+            ComplexSelectorChild::Combinator(combinator) => matches!(
+                combinator.kind,
+                CombinatorKind::Child | CombinatorKind::Descendant
+            ),
+        }),
+        _ => false,
+    }
+}
+
+/// Caller must make sure the selector list passes [`is_less_mixin_callee`].
+pub(super) fn convert_less_mixin_callee(
+    selector_list: SelectorList<'_>,
+) -> Vec<LessMixinCalleeSegment<'_>> {
+    let mut callee = Vec::with_capacity(1);
+    let mut combinator = None;
+    for child in selector_list
+        .selectors
+        .into_iter()
+        .flat_map(|complex| complex.children)
+    {
+        match child {
+            ComplexSelectorChild::CompoundSelector(compound) => {
+                callee.extend(compound.children.into_iter().filter_map(|simple| {
+                    let name = match simple {
+                        SimpleSelector::Class(class) => LessMixinName::ClassSelector(class),
+                        SimpleSelector::Id(id) => LessMixinName::IdSelector(id),
+                        _ => return None,
+                    };
+                    let combinator = combinator.take();
+                    let mut span = name.span().clone();
+                    if let Some(Combinator {
+                        span: Span { start, .. },
+                        ..
+                    }) = &combinator
+                    {
+                        span.start = *start;
+                    }
+                    Some(LessMixinCalleeSegment {
+                        combinator,
+                        name,
+                        span,
+                    })
+                }));
+            }
+            ComplexSelectorChild::Combinator(child) => combinator = Some(child),
+        }
+    }
+    callee
+}
+
+fn convert_less_function(value: ComponentValue<'_>) -> ComponentValue<'_> {
+    match value {
+        ComponentValue::Function(Function {
+            name: InterpolableIdent::Literal(name),
+            args,
+            span,
+        }) if is_less_color_function(&name.name) => {
+            ComponentValue::LessFunction(LessFunction { name, args, span })
+        }
+        value => value,
+    }
+}
+
+// https://lesscss.org/functions/#color-operations
+fn is_less_color_function(name: &str) -> bool {
+    [
+        "saturate",
+        "desaturate",
+        "lighten",
+        "darken",
+        "fadein",
+        "fadeout",
+        "fade",
+        "spin",
+        "mix",
+        "tint",
+        "shade",
+        "greyscale",
+        "contrast",
+    ]
+    .iter()
+    .any(|function| name.eq_ignore_ascii_case(function))
+}
+
+fn is_less_type_function(name: &str) -> bool {
+    [
+        "iscolor",
+        "isnumber",
+        "isstring",
+        "iskeyword",
+        "isurl",
+        "ispixel",
+        "isem",
+        "ispercentage",
+        "isruleset",
+        "isdefined",
+        "islist",
+    ]
+    .iter()
+    .any(|function| name.eq_ignore_ascii_case(function))
+}
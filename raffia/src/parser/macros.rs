@@ -2,13 +2,9 @@
 #[macro_export]
 macro_rules! bump {
     ($parser:expr) => {{
-        match $parser.cached_token.take() {
-            Some(token_with_span) => token_with_span,
-            None => {
-                let tokenizer = &mut $parser.tokenizer;
-                tokenizer.bump()?
-            }
-        }
+        let token_with_span = $parser.tokenizer.bump()?;
+        $parser.take_tokenizer_errors();
+        token_with_span
     }};
 }
 
@@ -17,16 +13,11 @@ macro_rules! bump {
 macro_rules! expect {
     ($parser:expr, $variant:ident) => {{
         use $crate::{
+            bump,
             error::{Error, ErrorKind},
             tokenizer::{Token, TokenSymbol, TokenWithSpan},
         };
-        let token_with_span = match $parser.cached_token.take() {
-            Some(token) => token,
-            None => {
-                let tokenizer = &mut $parser.tokenizer;
-                tokenizer.bump()?
-            }
-        };
+        let token_with_span = bump!($parser);
         match token_with_span {
             TokenWithSpan {
                 token: Token::$variant(token),
@@ -53,7 +44,7 @@ macro_rules! expect_without_ws_or_comments {
             error::{Error, ErrorKind},
             tokenizer::TokenSymbol,
         };
-        debug_assert!($parser.cached_token.is_none());
+        debug_assert!(!$parser.tokenizer.has_lookahead());
         let tokenizer = &mut $parser.tokenizer;
         if tokenizer.is_start_of_ident() {
             tokenizer.scan_ident_sequence()?
@@ -73,7 +64,7 @@ macro_rules! expect_without_ws_or_comments {
             error::{Error, ErrorKind},
             tokenizer::{Token, TokenSymbol, TokenWithSpan},
         };
-        debug_assert!($parser.cached_token.is_none());
+        debug_assert!(!$parser.tokenizer.has_lookahead());
         let tokenizer = &mut $parser.tokenizer;
         let token_with_span = tokenizer.bump_without_ws_or_comments()?;
         match token_with_span {
@@ -99,19 +90,20 @@ macro_rules! expect_without_ws_or_comments {
 macro_rules! eat {
     ($parser:expr, $variant:ident) => {{
         use $crate::{
-            bump,
+            bump, peek,
             tokenizer::{Token, TokenWithSpan},
         };
-        let token_with_span = bump!($parser);
-        match token_with_span {
-            TokenWithSpan {
-                token: Token::$variant(token),
-                span,
-            } => Some((token, span)),
-            value => {
-                $parser.cached_token = Some(value);
-                None
+        if let Token::$variant(..) = &peek!($parser).token {
+            match bump!($parser) {
+                TokenWithSpan {
+                    token: Token::$variant(token),
+                    span,
+                } => Some((token, span)),
+                // SAFETY: We've checked the kind of peeked token above.
+                _ => unsafe { std::hint::unreachable_unchecked() },
             }
+        } else {
+            None
         }
     }};
 }
@@ -120,15 +112,8 @@ macro_rules! eat {
 #[macro_export]
 macro_rules! peek {
     ($parser:expr) => {{
-        match &$parser.cached_token {
-            Some(token_with_span) => token_with_span,
-            None => {
-                let tokenizer = &mut $parser.tokenizer;
-                let token = tokenizer.bump()?;
-                $parser.cached_token = Some(token);
-                // SAFETY: We've written `Some(..)` value to `cached_token`, so it won't be `None`.
-                unsafe { $parser.cached_token.as_ref().unwrap_unchecked() }
-            }
-        }
+        $parser.tokenizer.peek_nth(0)?;
+        $parser.take_tokenizer_errors();
+        $parser.tokenizer.peeked()
     }};
 }
@@ -1,11 +1,14 @@
 use self::state::ParserState;
 use crate::{
-    config::Syntax,
+    ast::{ComponentValue, SimpleBlock, Statement, Stylesheet},
+    config::{ParserOptions, Syntax},
     error::{Error, ErrorKind, PResult},
+    expect,
     pos::Span,
-    tokenizer::{token::TokenWithSpan, Tokenizer},
+    tokenizer::Tokenizer,
 };
 pub use builder::ParserBuilder;
+use std::mem;
 
 mod at_rule;
 mod builder;
@@ -26,10 +29,10 @@ pub trait Parse<'cmt, 's: 'cmt>: Sized {
 pub struct Parser<'cmt, 's: 'cmt> {
     source: &'s str,
     syntax: Syntax,
+    options: ParserOptions,
     tokenizer: Tokenizer<'cmt, 's>,
     state: ParserState,
     recoverable_errors: Vec<Error>,
-    cached_token: Option<TokenWithSpan<'s>>,
 }
 
 impl<'cmt, 's: 'cmt> Parser<'cmt, 's> {
@@ -39,10 +42,10 @@ impl<'cmt, 's: 'cmt> Parser<'cmt, 's> {
         Parser {
             source,
             syntax: syntax.clone(),
+            options: Default::default(),
             tokenizer: Tokenizer::new(source, syntax, None),
             state: Default::default(),
             recoverable_errors: vec![],
-            cached_token: None,
         }
     }
 
@@ -54,33 +57,164 @@ impl<'cmt, 's: 'cmt> Parser<'cmt, 's> {
         T::parse(self)
     }
 
+    /// Parse a full stylesheet until the end of file.
+    ///
+    /// This is a shortcut of `parser.parse::<Stylesheet>()`.
+    /// Recoverable errors during parsing are collected,
+    /// and can be retrieved via [`recoverable_errors`](Parser::recoverable_errors).
+    ///
+    /// ```rust
+    /// use raffia::{Parser, Syntax};
+    ///
+    /// let mut parser = Parser::new("$c: red; a { color: $c; } @media print { b {} }", Syntax::Scss);
+    /// let stylesheet = parser.parse_stylesheet().unwrap();
+    /// assert_eq!(stylesheet.statements.len(), 3);
+    /// assert!(stylesheet.statements[0].is_sass_variable_declaration());
+    /// assert!(stylesheet.statements[1].is_qualified_rule());
+    /// assert!(stylesheet.statements[2].is_at_rule());
+    /// assert!(parser.recoverable_errors().is_empty());
+    /// ```
+    pub fn parse_stylesheet(&mut self) -> PResult<Stylesheet<'s>> {
+        self.parse()
+    }
+
+    /// Parse a block wrapped with braces, such as `{ color: red; }`.
+    ///
+    /// Besides declarations, nested rules and at-rules are allowed in the block.
+    ///
+    /// ```rust
+    /// use raffia::{Parser, Syntax};
+    ///
+    /// let mut parser = Parser::new("{ color: red; & > a { margin: 0 } }", Syntax::Css);
+    /// let block = parser.parse_declaration_block().unwrap();
+    /// assert_eq!(block.statements.len(), 2);
+    /// assert!(block.statements[0].is_declaration());
+    /// assert!(block.statements[1].is_qualified_rule());
+    /// ```
+    pub fn parse_declaration_block(&mut self) -> PResult<SimpleBlock<'s>> {
+        self.parse()
+    }
+
+    /// Parse a list of declarations without braces until the end of file,
+    /// such as the value of HTML `style` attribute.
+    ///
+    /// ```rust
+    /// use raffia::{Parser, Syntax};
+    ///
+    /// let mut parser = Parser::new("color: red; margin: 0 auto", Syntax::Css);
+    /// let statements = parser.parse_declaration_list().unwrap();
+    /// assert_eq!(statements.len(), 2);
+    /// assert!(statements.iter().all(|statement| statement.is_declaration()));
+    ///
+    /// let mut parser = Parser::new("color: red; }", Syntax::Css);
+    /// assert!(parser.parse_declaration_list().is_err());
+    /// ```
+    pub fn parse_declaration_list(&mut self) -> PResult<Vec<Statement<'s>>> {
+        let statements = self.parse_statements(/* is_top_level */ false)?;
+        expect!(self, Eof);
+        Ok(statements)
+    }
+
+    /// Parse a single component value until the end of file,
+    /// such as a property value held outside of any stylesheet.
+    ///
+    /// To parse a list of component values like `10px solid red`,
+    /// use [`parse::<ComponentValues>`](Parser::parse) instead.
+    ///
+    /// ```rust
+    /// use raffia::{Parser, Syntax};
+    ///
+    /// let mut parser = Parser::new("calc(100% - 10px)", Syntax::Css);
+    /// let value = parser.parse_component_value().unwrap();
+    /// assert!(value.is_function());
+    ///
+    /// let mut parser = Parser::new("10px solid", Syntax::Css);
+    /// assert!(parser.parse_component_value().is_err());
+    /// ```
+    pub fn parse_component_value(&mut self) -> PResult<ComponentValue<'s>> {
+        let value = self.parse()?;
+        expect!(self, Eof);
+        Ok(value)
+    }
+
     /// Retrieve recoverable errors.
     #[inline]
     pub fn recoverable_errors(&self) -> &[Error] {
         &self.recoverable_errors
     }
 
+    /// Retrieve current syntax.
+    #[inline]
+    pub fn syntax(&self) -> Syntax {
+        self.syntax.clone()
+    }
+
+    /// Parse with another syntax temporarily.
+    /// Original syntax will be restored after `f` returns.
+    ///
+    /// This is useful for documents which mix code in different syntaxes.
+    ///
+    /// ```rust
+    /// use raffia::{ast::Stylesheet, Parser, Syntax};
+    ///
+    /// let mut parser = Parser::new("$width: 1px;", Syntax::Css);
+    /// let stylesheet = parser.with_syntax(Syntax::Scss, |parser| {
+    ///     assert_eq!(parser.syntax(), Syntax::Scss);
+    ///     parser.parse::<Stylesheet>()
+    /// });
+    /// assert!(stylesheet.unwrap().statements[0].is_sass_variable_declaration());
+    /// assert_eq!(parser.syntax(), Syntax::Css);
+    /// ```
+    ///
+    /// Token which has been looked ahead will be scanned again with new syntax,
+    /// so `//` below is treated as a line comment instead of two solidi:
+    ///
+    /// ```rust
+    /// use raffia::{
+    ///     ast::{SelectorList, SimpleBlock},
+    ///     Parser, Syntax,
+    /// };
+    ///
+    /// let mut parser = Parser::new("a // note\n{ color: red }", Syntax::Css);
+    /// parser.parse::<SelectorList>().unwrap();
+    /// let block = parser.with_syntax(Syntax::Scss, |parser| parser.parse::<SimpleBlock>());
+    /// assert_eq!(block.unwrap().statements.len(), 1);
+    /// ```
+    pub fn with_syntax<R, F>(&mut self, syntax: Syntax, f: F) -> R
+    where
+        F: FnOnce(&mut Self) -> R,
+    {
+        let original_syntax = self.switch_syntax(syntax);
+        let result = f(self);
+        self.switch_syntax(original_syntax);
+        result
+    }
+
+    fn switch_syntax(&mut self, syntax: Syntax) -> Syntax {
+        // peeked token was scanned with previous syntax, so it must be scanned again
+        self.tokenizer.discard_lookahead();
+        self.tokenizer.syntax = syntax.clone();
+        mem::replace(&mut self.syntax, syntax)
+    }
+
     fn try_parse<R, F: Fn(&mut Self) -> PResult<R>>(&mut self, f: F) -> PResult<R> {
-        let tokenizer_state = self.tokenizer.state.clone();
-        let comments_count = if let Some(comments) = &self.tokenizer.comments {
-            comments.len()
-        } else {
-            0
-        };
+        self.take_tokenizer_errors();
+        let checkpoint = self.tokenizer.checkpoint();
         let recoverable_errors_count = self.recoverable_errors.len();
-        let cached_token = self.cached_token.clone();
         let result = f(self);
         if result.is_err() {
-            self.tokenizer.state = tokenizer_state;
-            if let Some(comments) = &mut self.tokenizer.comments {
-                comments.truncate(comments_count);
-            }
+            self.tokenizer.restore(checkpoint);
             self.recoverable_errors.truncate(recoverable_errors_count);
-            self.cached_token = cached_token;
         }
         result
     }
 
+    /// Move recoverable errors reported by tokenizer to parser.
+    #[inline]
+    fn take_tokenizer_errors(&mut self) {
+        self.recoverable_errors.append(&mut self.tokenizer.errors);
+    }
+
     #[must_use]
     fn assert_no_ws_or_comment(&self, left: &Span, right: &Span) -> PResult<()> {
         debug_assert!(left.end <= right.start);
@@ -1,4 +1,7 @@
-use super::{state::ParserState, Parser};
+use super::{
+    state::{ParserState, QualifiedRuleContext},
+    Parser,
+};
 use crate::{
     ast::*,
     bump,
@@ -19,6 +22,20 @@ const PRECEDENCE_AND: u8 = 2;
 const PRECEDENCE_OR: u8 = 1;
 
 impl<'cmt, 's: 'cmt> Parser<'cmt, 's> {
+    /// Positional arguments must come before keyword arguments.
+    fn check_sass_positional_argument(
+        &mut self,
+        args: &[SassIncludeAtRuleArgument<'s>],
+        span: &Span,
+    ) {
+        if args.iter().any(|arg| arg.name.is_some()) {
+            self.recoverable_errors.push(Error {
+                kind: ErrorKind::SassPositionalArgumentAfterKeyword,
+                span: span.clone(),
+            });
+        }
+    }
+
     pub(super) fn parse_sass_at_rule(
         &mut self,
         at_keyword_name: &str,
@@ -80,6 +97,24 @@ impl<'cmt, 's: 'cmt> Parser<'cmt, 's> {
 
         loop {
             let operator = match &peek!(self).token {
+                Token::Solidus(..) if precedence == PRECEDENCE_MULTIPLY => {
+                    match self.try_parse(|parser| parser.parse_sass_division(&left)) {
+                        Ok((operator, right)) => {
+                            let span = Span {
+                                start: left.span().start,
+                                end: right.span().end,
+                            };
+                            left = ComponentValue::SassBinaryExpression(SassBinaryExpression {
+                                left: Box::new(left),
+                                op: operator,
+                                right: Box::new(right),
+                                span,
+                            });
+                            continue;
+                        }
+                        Err(..) => break,
+                    }
+                }
                 Token::Asterisk(..) if precedence == PRECEDENCE_MULTIPLY => SassBinaryOperator {
                     kind: SassBinaryOperatorKind::Multiply,
                     span: bump!(self).span,
@@ -159,6 +194,64 @@ impl<'cmt, 's: 'cmt> Parser<'cmt, 's> {
         Ok(left)
     }
 
+    /// Sass treats `/` as division or as separator depending on its operands:
+    ///
+    /// - both operands must be numbers or something that may produce numbers,
+    ///   such as variables, function calls or other expressions;
+    /// - in declaration value, at least one of them mustn't be number literal,
+    ///   so `font: 12px/1.5` is kept as separator while `$a / $b` is division;
+    /// - anywhere else, like in variable declaration or parentheses,
+    ///   `6/3` is division.
+    fn parse_sass_division(
+        &mut self,
+        left: &ComponentValue<'s>,
+    ) -> PResult<(SassBinaryOperator, ComponentValue<'s>)> {
+        let span = expect!(self, Solidus).1;
+        let right = self.parse_sass_unary_expression()?;
+
+        let is_literal = |value: &ComponentValue| {
+            matches!(
+                value,
+                ComponentValue::Number(..)
+                    | ComponentValue::Dimension(..)
+                    | ComponentValue::Percentage(..)
+            )
+        };
+        let is_numeric = |value: &ComponentValue| {
+            is_literal(value)
+                || matches!(
+                    value,
+                    ComponentValue::Function(..)
+                        | ComponentValue::SassBinaryExpression(..)
+                        | ComponentValue::SassNamespacedExpression(..)
+                        | ComponentValue::SassParenthesizedExpression(..)
+                        | ComponentValue::SassUnaryExpression(..)
+                        | ComponentValue::SassVariable(..)
+                )
+        };
+        let is_division = is_numeric(left)
+            && is_numeric(&right)
+            && (!matches!(
+                self.state.qualified_rule_ctx,
+                Some(QualifiedRuleContext::DeclarationValue)
+            ) || !is_literal(left)
+                || !is_literal(&right));
+        if is_division {
+            Ok((
+                SassBinaryOperator {
+                    kind: SassBinaryOperatorKind::Division,
+                    span,
+                },
+                right,
+            ))
+        } else {
+            Err(Error {
+                kind: ErrorKind::TryParseError,
+                span,
+            })
+        }
+    }
+
     pub(super) fn parse_sass_interpolated_ident(&mut self) -> PResult<InterpolableIdent<'s>> {
         debug_assert!(matches!(self.syntax, Syntax::Scss | Syntax::Sass));
         let (first, mut span) = match peek!(self) {
@@ -793,12 +886,21 @@ impl<'cmt, 's: 'cmt> Parse<'cmt, 's> for SassIncludeAtRule<'s> {
                         } else if let Ok(arbitrary_arg) =
                             input.try_parse(SassArbitraryArgument::parse)
                         {
+                            // both list and map can be spread, and they look the same,
+                            // but anyway it must be the last argument
+                            if let Token::Comma(..) = &peek!(input).token {
+                                return Err(Error {
+                                    kind: ErrorKind::SassArbitraryArgumentNotLast,
+                                    span: arbitrary_arg.span,
+                                });
+                            }
                             arbitrary_argument = Some(arbitrary_arg);
                             expect!(input, RParen);
                             break;
                         } else {
                             let value = input.parse::<ComponentValue>()?;
                             let span = value.span().clone();
+                            input.check_sass_positional_argument(&args, &span);
                             args.push(SassIncludeAtRuleArgument {
                                 name: None,
                                 value,
@@ -809,6 +911,7 @@ impl<'cmt, 's: 'cmt> Parse<'cmt, 's> for SassIncludeAtRule<'s> {
                     _ => {
                         let value = input.parse::<ComponentValue>()?;
                         let span = value.span().clone();
+                        input.check_sass_positional_argument(&args, &span);
                         args.push(SassIncludeAtRuleArgument {
                             name: None,
                             value,
@@ -946,6 +1049,22 @@ impl<'cmt, 's: 'cmt> Parse<'cmt, 's> for SassInterpolatedUrl<'s> {
     }
 }
 
+impl<'cmt, 's: 'cmt> Parse<'cmt, 's> for SassList<'s> {
+    fn parse(input: &mut Parser<'cmt, 's>) -> PResult<Self> {
+        let start = expect!(input, LParen).1.start;
+        let elements = input
+            .parse_component_values(
+                /* allow_comma */ true, /* allow_semicolon */ false,
+            )?
+            .values;
+        let end = expect!(input, RParen).1.end;
+        Ok(SassList {
+            elements,
+            span: Span { start, end },
+        })
+    }
+}
+
 impl<'cmt, 's: 'cmt> Parse<'cmt, 's> for SassMap<'s> {
     fn parse(input: &mut Parser<'cmt, 's>) -> PResult<Self> {
         let start = expect!(input, LParen).1.start;
@@ -1071,7 +1190,14 @@ impl<'cmt, 's: 'cmt> Parse<'cmt, 's> for SassNestingDeclaration<'s> {
 impl<'cmt, 's: 'cmt> Parse<'cmt, 's> for SassParenthesizedExpression<'s> {
     fn parse(input: &mut Parser<'cmt, 's>) -> PResult<Self> {
         let start = expect!(input, LParen).1.start;
-        let expr = Box::new(input.parse()?);
+        let expr = Box::new(
+            input
+                .with_state(ParserState {
+                    qualified_rule_ctx: None,
+                    ..input.state
+                })
+                .parse()?,
+        );
         let end = expect!(input, RParen).1.end;
         Ok(SassParenthesizedExpression {
             expr,
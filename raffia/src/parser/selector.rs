@@ -7,7 +7,7 @@ use crate::{
     pos::{Span, Spanned},
     tokenizer::{token, Token, TokenWithSpan},
     util::{handle_escape, CowStr, LastOfNonEmpty, PairedToken},
-    Parse, Syntax,
+    AstNode, Parse, Syntax, Walk,
 };
 use smallvec::SmallVec;
 
@@ -451,94 +451,7 @@ impl<'cmt, 's: 'cmt> Parse<'cmt, 's> for AttributeSelector<'s> {
     fn parse(input: &mut Parser<'cmt, 's>) -> PResult<Self> {
         let start = expect!(input, LBracket).1.start;
 
-        let name = match peek!(input) {
-            TokenWithSpan {
-                token: Token::Ident(..) | Token::HashLBrace(..) | Token::AtLBraceVar(..),
-                ..
-            } => {
-                let ident = input.parse::<InterpolableIdent>()?;
-                let ident_span = ident.span();
-                if let Some((_, bar_token_span)) = eat!(input, Bar) {
-                    input.assert_no_ws_or_comment(ident_span, &bar_token_span)?;
-
-                    let name = input.parse::<InterpolableIdent>()?;
-                    let name_span = name.span();
-                    input.assert_no_ws_or_comment(&bar_token_span, name_span)?;
-
-                    let start = ident_span.start;
-                    let end = name_span.end;
-                    WqName {
-                        name,
-                        prefix: Some(NsPrefix {
-                            kind: Some(NsPrefixKind::Ident(ident)),
-                            span: Span {
-                                start,
-                                end: bar_token_span.end,
-                            },
-                        }),
-                        span: Span { start, end },
-                    }
-                } else {
-                    let span = ident_span.clone();
-                    WqName {
-                        name: ident,
-                        prefix: None,
-                        span,
-                    }
-                }
-            }
-            TokenWithSpan {
-                token: Token::Asterisk(..),
-                ..
-            } => {
-                let asterisk_span = bump!(input).span;
-                let bar_token_span = expect!(input, Bar).1;
-                let name = input.parse::<InterpolableIdent>()?;
-
-                let start = asterisk_span.start;
-                let end = name.span().end;
-                WqName {
-                    name,
-                    prefix: Some(NsPrefix {
-                        kind: Some(NsPrefixKind::Universal(NsPrefixUniversal {
-                            span: asterisk_span,
-                        })),
-                        span: Span {
-                            start,
-                            end: bar_token_span.end,
-                        },
-                    }),
-                    span: Span { start, end },
-                }
-            }
-            TokenWithSpan {
-                token: Token::Bar(..),
-                ..
-            } => {
-                let bar_token_span = bump!(input).span;
-                let name = input.parse::<InterpolableIdent>()?;
-
-                let start = bar_token_span.start;
-                let end = name.span().end;
-                WqName {
-                    name,
-                    prefix: Some(NsPrefix {
-                        kind: None,
-                        span: Span {
-                            start,
-                            end: bar_token_span.end,
-                        },
-                    }),
-                    span: Span { start, end },
-                }
-            }
-            TokenWithSpan { span, .. } => {
-                return Err(Error {
-                    kind: ErrorKind::ExpectWqName,
-                    span: span.clone(),
-                });
-            }
-        };
+        let name = input.parse::<WqName>()?;
 
         let matcher = match peek!(input) {
             TokenWithSpan {
@@ -683,6 +596,7 @@ impl<'cmt, 's: 'cmt> Parse<'cmt, 's> for ComplexSelector<'s> {
 
         children.push(ComplexSelectorChild::CompoundSelector(first));
         while let Some(combinator) = input.parse_combinator(end)? {
+            input.assert_combinator_not_dangling(&combinator)?;
             children.push(ComplexSelectorChild::Combinator(combinator));
             let compound_selector = input.parse::<CompoundSelector>()?;
             end = compound_selector.span.end;
@@ -773,6 +687,8 @@ impl<'cmt, 's: 'cmt> Parse<'cmt, 's> for IdSelector<'s> {
                     end: span.end,
                 };
                 let raw = token.raw;
+                // check raw text instead of decoded name,
+                // because escaped digit like `#\31 a` is a valid identifier start
                 if raw.starts_with(|c: char| c.is_ascii_digit()) {
                     input.recoverable_errors.push(Error {
                         kind: ErrorKind::InvalidIdSelectorName,
@@ -873,29 +789,65 @@ impl<'cmt, 's: 'cmt> Parse<'cmt, 's> for NestingSelector {
 
 impl<'cmt, 's: 'cmt> Parse<'cmt, 's> for Nth<'s> {
     fn parse(input: &mut Parser<'cmt, 's>) -> PResult<Self> {
-        match &peek!(input).token {
-            Token::Ident(ident) => {
-                let name = ident.name();
-                if name.eq_ignore_ascii_case("odd") {
-                    input.parse().map(Nth::Odd)
-                } else if name.eq_ignore_ascii_case("even") {
-                    input.parse().map(Nth::Even)
-                } else {
-                    input.parse().map(Nth::AnPlusB)
+        if matches!(input.syntax, Syntax::Scss | Syntax::Sass) {
+            let nth = input.try_parse(|parser| {
+                let nth = parser.parse_nth()?;
+                match peek!(parser) {
+                    TokenWithSpan {
+                        token: Token::RParen(..),
+                        ..
+                    } => Ok(nth),
+                    TokenWithSpan {
+                        token: Token::Ident(ident),
+                        ..
+                    } if ident.name().eq_ignore_ascii_case("of") => Ok(nth),
+                    TokenWithSpan { span, .. } => Err(Error {
+                        kind: ErrorKind::TryParseError,
+                        span: span.clone(),
+                    }),
                 }
-            }
-            Token::Number(..) => {
-                let number = input.parse::<Number>()?;
-                if number.value.fract() == 0.0 {
-                    Ok(Nth::Integer(number))
-                } else {
-                    Err(Error {
-                        kind: ErrorKind::ExpectInteger,
-                        span: number.span,
-                    })
+            });
+            let error = match nth {
+                Ok(nth) => return Ok(nth),
+                Err(error) => error,
+            };
+            let values = input.try_parse(|parser| {
+                // stop at `of` for `:nth-child(#{$n} of .foo)`
+                let first = parser.parse::<ComponentValue>()?;
+                let mut span = first.span().clone();
+                let mut values = vec![first];
+                loop {
+                    match &peek!(parser).token {
+                        Token::RParen(..) | Token::Eof(..) => break,
+                        Token::Ident(ident) if ident.name().eq_ignore_ascii_case("of") => break,
+                        _ => values.push(parser.parse()?),
+                    }
+                }
+                span.end = values.last_of_non_empty().span().end;
+                Ok(ComponentValues { values, span })
+            });
+            match values {
+                Ok(values) => {
+                    let mut interpolated = false;
+                    values.walk(&mut |node| {
+                        interpolated |= matches!(
+                            node,
+                            AstNode::ComponentValue(ComponentValue::InterpolableIdent(
+                                InterpolableIdent::SassInterpolated(..)
+                            ))
+                        );
+                        !interpolated
+                    });
+                    if interpolated {
+                        Ok(Nth::Interpolated(values))
+                    } else {
+                        Err(error)
+                    }
                 }
+                Err(..) => Err(error),
             }
-            _ => input.parse().map(Nth::AnPlusB),
+        } else {
+            input.parse_nth()
         }
     }
 }
@@ -925,8 +877,29 @@ impl<'cmt, 's: 'cmt> Parse<'cmt, 's> for PseudoClassSelector<'s> {
                 let arg = match &name {
                     InterpolableIdent::Literal(Ident { name, .. })
                         if name.eq_ignore_ascii_case("nth-child")
-                            || name.eq_ignore_ascii_case("nth-last-child")
-                            || name.eq_ignore_ascii_case("nth-of-type")
+                            || name.eq_ignore_ascii_case("nth-last-child") =>
+                    {
+                        // https://drafts.csswg.org/selectors-4/#the-nth-child-pseudo
+                        let nth = input.parse::<Nth>()?;
+                        match &peek!(input).token {
+                            Token::Ident(ident) if ident.name().eq_ignore_ascii_case("of") => {
+                                bump!(input);
+                                let selector_list = input.parse::<SelectorList>()?;
+                                let span = Span {
+                                    start: nth.span().start,
+                                    end: selector_list.span.end,
+                                };
+                                PseudoClassSelectorArg::NthOf(NthOf {
+                                    nth,
+                                    selector_list: Box::new(selector_list),
+                                    span,
+                                })
+                            }
+                            _ => PseudoClassSelectorArg::Nth(nth),
+                        }
+                    }
+                    InterpolableIdent::Literal(Ident { name, .. })
+                        if name.eq_ignore_ascii_case("nth-of-type")
                             || name.eq_ignore_ascii_case("nth-last-of-type")
                             || name.eq_ignore_ascii_case("nth-col")
                             || name.eq_ignore_ascii_case("nth-last-col") =>
@@ -934,8 +907,30 @@ impl<'cmt, 's: 'cmt> Parse<'cmt, 's> for PseudoClassSelector<'s> {
                         input.parse().map(PseudoClassSelectorArg::Nth)?
                     }
                     InterpolableIdent::Literal(Ident { name, .. })
-                        if name.eq_ignore_ascii_case("not")
-                            || name.eq_ignore_ascii_case("is")
+                        if name.eq_ignore_ascii_case("not") =>
+                    {
+                        let selector_list = input.parse::<SelectorList>()?;
+                        // Selectors Level 3 only allows single simple selector
+                        if input.options.strict_not_pseudo_class
+                            && !matches!(
+                                &selector_list.selectors[..],
+                                [ComplexSelector { children, .. }]
+                                    if matches!(
+                                        &children[..],
+                                        [ComplexSelectorChild::CompoundSelector(compound)]
+                                            if compound.children.len() == 1
+                                    )
+                            )
+                        {
+                            return Err(Error {
+                                kind: ErrorKind::ExpectSingleSimpleSelector,
+                                span: selector_list.span,
+                            });
+                        }
+                        PseudoClassSelectorArg::SelectorList(Box::new(selector_list))
+                    }
+                    InterpolableIdent::Literal(Ident { name, .. })
+                        if name.eq_ignore_ascii_case("is")
                             || name.eq_ignore_ascii_case("where")
                             || name.eq_ignore_ascii_case("matches") =>
                     {
@@ -1064,6 +1059,9 @@ impl<'cmt, 's: 'cmt> Parse<'cmt, 's> for RelativeSelector<'s> {
             }) => None,
             combinator => combinator,
         };
+        if let Some(combinator) = &combinator {
+            input.assert_combinator_not_dangling(combinator)?;
+        }
         let complex_selector = input.parse::<ComplexSelector>()?;
         let mut span = complex_selector.span.clone();
         if let Some(combinator) = &combinator {
@@ -1277,9 +1275,154 @@ impl<'cmt, 's: 'cmt> Parse<'cmt, 's> for TypeSelector<'s> {
     }
 }
 
+impl<'cmt, 's: 'cmt> Parse<'cmt, 's> for WqName<'s> {
+    fn parse(input: &mut Parser<'cmt, 's>) -> PResult<Self> {
+        let wq_name = match peek!(input) {
+            TokenWithSpan {
+                token: Token::Ident(..) | Token::HashLBrace(..) | Token::AtLBraceVar(..),
+                ..
+            } => {
+                let ident = input.parse::<InterpolableIdent>()?;
+                let ident_span = ident.span();
+                if let Some((_, bar_token_span)) = eat!(input, Bar) {
+                    input.assert_no_ws_or_comment(ident_span, &bar_token_span)?;
+
+                    let name = input.parse::<InterpolableIdent>()?;
+                    let name_span = name.span();
+                    input.assert_no_ws_or_comment(&bar_token_span, name_span)?;
+
+                    let start = ident_span.start;
+                    let end = name_span.end;
+                    WqName {
+                        name,
+                        prefix: Some(NsPrefix {
+                            kind: Some(NsPrefixKind::Ident(ident)),
+                            span: Span {
+                                start,
+                                end: bar_token_span.end,
+                            },
+                        }),
+                        span: Span { start, end },
+                    }
+                } else {
+                    let span = ident_span.clone();
+                    WqName {
+                        name: ident,
+                        prefix: None,
+                        span,
+                    }
+                }
+            }
+            TokenWithSpan {
+                token: Token::Asterisk(..),
+                ..
+            } => {
+                let asterisk_span = bump!(input).span;
+                let bar_token_span = expect!(input, Bar).1;
+                let name = input.parse::<InterpolableIdent>()?;
+
+                let start = asterisk_span.start;
+                let end = name.span().end;
+                WqName {
+                    name,
+                    prefix: Some(NsPrefix {
+                        kind: Some(NsPrefixKind::Universal(NsPrefixUniversal {
+                            span: asterisk_span,
+                        })),
+                        span: Span {
+                            start,
+                            end: bar_token_span.end,
+                        },
+                    }),
+                    span: Span { start, end },
+                }
+            }
+            TokenWithSpan {
+                token: Token::Bar(..),
+                ..
+            } => {
+                let bar_token_span = bump!(input).span;
+                let name = input.parse::<InterpolableIdent>()?;
+
+                let start = bar_token_span.start;
+                let end = name.span().end;
+                WqName {
+                    name,
+                    prefix: Some(NsPrefix {
+                        kind: None,
+                        span: Span {
+                            start,
+                            end: bar_token_span.end,
+                        },
+                    }),
+                    span: Span { start, end },
+                }
+            }
+            TokenWithSpan { span, .. } => {
+                return Err(Error {
+                    kind: ErrorKind::ExpectWqName,
+                    span: span.clone(),
+                });
+            }
+        };
+        Ok(wq_name)
+    }
+}
+
 impl<'cmt, 's: 'cmt> Parser<'cmt, 's> {
+    fn parse_nth(&mut self) -> PResult<Nth<'s>> {
+        match &peek!(self).token {
+            Token::Ident(ident) => {
+                let name = ident.name();
+                if name.eq_ignore_ascii_case("odd") {
+                    self.parse().map(Nth::Odd)
+                } else if name.eq_ignore_ascii_case("even") {
+                    self.parse().map(Nth::Even)
+                } else {
+                    self.parse().map(Nth::AnPlusB)
+                }
+            }
+            Token::Number(..) => {
+                let number = self.parse::<Number>()?;
+                if number.value.fract() == 0.0 {
+                    Ok(Nth::Integer(number))
+                } else {
+                    Err(Error {
+                        kind: ErrorKind::ExpectInteger,
+                        span: number.span,
+                    })
+                }
+            }
+            _ => self.parse().map(Nth::AnPlusB),
+        }
+    }
+
+    fn assert_combinator_not_dangling(&mut self, combinator: &Combinator) -> PResult<()> {
+        match &peek!(self).token {
+            Token::LBrace(..)
+            | Token::RBrace(..)
+            | Token::RParen(..)
+            | Token::Comma(..)
+            | Token::Semicolon(..)
+            | Token::Indent(..)
+            | Token::Linebreak(..)
+            | Token::Eof(..) => Err(Error {
+                kind: ErrorKind::DanglingCombinator,
+                span: combinator.span.clone(),
+            }),
+            _ => Ok(()),
+        }
+    }
+
     fn parse_combinator(&mut self, pos: usize) -> PResult<Option<Combinator>> {
         match peek!(self) {
+            // guard of Less guarded rule, not a type selector
+            TokenWithSpan {
+                token: Token::Ident(ident),
+                ..
+            } if self.syntax == Syntax::Less && ident.name().eq_ignore_ascii_case("when") => {
+                Ok(None)
+            }
             TokenWithSpan {
                 token:
                     Token::Ident(..)
@@ -1290,6 +1433,7 @@ impl<'cmt, 's: 'cmt> Parser<'cmt, 's> {
                     | Token::LBracket(..)
                     | Token::Asterisk(..)
                     | Token::Ampersand(..)
+                    | Token::HashLBrace(..)
                     | Token::Bar(..), // selector like `|type` (with <ns-prefix>)
                 span,
             } if pos < span.start => Ok(Some(Combinator {
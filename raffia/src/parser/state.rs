@@ -5,6 +5,9 @@ use std::ops::{Deref, DerefMut};
 pub(super) struct ParserState {
     pub(super) qualified_rule_ctx: Option<QualifiedRuleContext>,
     pub(super) in_sass_function: bool,
+    pub(super) in_font_face: bool,
+    pub(super) in_keyframes: bool,
+    pub(super) in_name_valued_property: bool,
 }
 
 #[derive(Clone, Debug)]
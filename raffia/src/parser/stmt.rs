@@ -1,4 +1,5 @@
 use super::{
+    less,
     state::{ParserState, QualifiedRuleContext},
     Parser,
 };
@@ -9,7 +10,7 @@ use crate::{
     expect, peek,
     pos::{Span, Spanned},
     tokenizer::{Token, TokenWithSpan},
-    util::PairedToken,
+    util::{self, PairedToken},
     Parse, Syntax,
 };
 
@@ -29,9 +30,13 @@ impl<'cmt, 's: 'cmt> Parse<'cmt, 's> for Declaration<'s> {
         };
 
         let (_, colon_span) = expect!(input, Colon);
-        let value = {
+        let mut value = {
             let mut parser = input.with_state(ParserState {
                 qualified_rule_ctx: Some(QualifiedRuleContext::DeclarationValue),
+                in_name_valued_property: matches!(
+                    &name,
+                    InterpolableIdent::Literal(ident) if util::is_name_valued_property(&ident.name)
+                ),
                 ..input.state
             });
             match &name {
@@ -40,11 +45,9 @@ impl<'cmt, 's: 'cmt> Parse<'cmt, 's> for Declaration<'s> {
                     let mut pairs = Vec::with_capacity(1);
                     loop {
                         match &peek!(parser).token {
-                            Token::RBrace(..)
-                            | Token::Semicolon(..)
-                            | Token::Dedent(..)
-                            | Token::Linebreak(..)
-                            | Token::Eof(..) => break,
+                            // `;` and `}` are allowed in nested blocks, like `--x: {a:b;}`
+                            Token::RBrace(..) | Token::Semicolon(..) if pairs.is_empty() => break,
+                            Token::Dedent(..) | Token::Linebreak(..) | Token::Eof(..) => break,
                             _ => {
                                 match &peek!(parser).token {
                                     Token::LParen(..) => {
@@ -82,33 +85,16 @@ impl<'cmt, 's: 'cmt> Parse<'cmt, 's> for Declaration<'s> {
                     }
                     values
                 }
-                _ => {
-                    let mut values = Vec::with_capacity(3);
-                    loop {
-                        match &peek!(parser).token {
-                            Token::RBrace(..)
-                            | Token::RParen(..)
-                            | Token::Semicolon(..)
-                            | Token::Dedent(..)
-                            | Token::Linebreak(..)
-                            | Token::Exclamation(..)
-                            | Token::Eof(..) => break,
-                            _ => {
-                                let value = parser.parse::<ComponentValue>()?;
-                                match &value {
-                                    ComponentValue::SassNestingDeclaration(..)
-                                        if matches!(parser.syntax, Syntax::Scss | Syntax::Sass) =>
-                                    {
-                                        values.push(value);
-                                        break;
-                                    }
-                                    _ => values.push(value),
-                                }
-                            }
-                        }
+                InterpolableIdent::Literal(ident)
+                    if parser.state.in_font_face && ident.name.eq_ignore_ascii_case("src") =>
+                {
+                    if let Ok(src) = parser.try_parse(FontFaceSrc::parse) {
+                        vec![ComponentValue::FontFaceSrc(src)]
+                    } else {
+                        parser.parse_declaration_value()?
                     }
-                    values
                 }
+                _ => parser.parse_declaration_value()?,
             }
         };
 
@@ -118,9 +104,43 @@ impl<'cmt, 's: 'cmt> Parse<'cmt, 's> for Declaration<'s> {
             None
         };
 
+        // values after `!important` are captured into declaration value when recovering
+        let mut has_trailing = false;
+        if important.is_some() {
+            match peek!(input) {
+                TokenWithSpan {
+                    token:
+                        Token::RBrace(..)
+                        | Token::Semicolon(..)
+                        | Token::Dedent(..)
+                        | Token::Linebreak(..)
+                        | Token::Eof(..),
+                    ..
+                } => {}
+                TokenWithSpan { span, .. } => {
+                    let start = span.start;
+                    let trailing = input.parse_declaration_value()?;
+                    let end = trailing.last().map_or(start, |last| last.span().end);
+                    let error = Error {
+                        kind: ErrorKind::ImportantNotLast,
+                        span: Span { start, end },
+                    };
+                    if input.options.strict_declaration_value {
+                        return Err(error);
+                    } else {
+                        input.recoverable_errors.push(error);
+                    }
+                    value.extend(trailing);
+                    has_trailing = true;
+                }
+            }
+        }
+
         let span = Span {
             start: name.span().start,
-            end: if let Some(important) = &important {
+            end: if let (true, Some(last)) = (has_trailing, value.last()) {
+                last.span().end
+            } else if let Some(important) = &important {
                 important.span.end
             } else if let Some(last) = value.last() {
                 last.span().end
@@ -128,6 +148,68 @@ impl<'cmt, 's: 'cmt> Parse<'cmt, 's> for Declaration<'s> {
                 colon_span.end
             },
         };
+
+        let is_custom_property =
+            matches!(&name, InterpolableIdent::Literal(ident) if ident.name.starts_with("--"));
+        // custom properties are allowed to have empty value
+        if value.is_empty() && !is_custom_property {
+            let error = Error {
+                kind: ErrorKind::EmptyDeclarationValue,
+                span: span.clone(),
+            };
+            if input.options.strict_declaration_value {
+                return Err(error);
+            } else {
+                input.recoverable_errors.push(error);
+            }
+        }
+
+        // https://drafts.csswg.org/css-values-4/#common-keywords
+        if input.options.strict_css_wide_keyword && !is_custom_property {
+            // nested properties block of Sass isn't a part of the value itself
+            let values = match value
+                .iter()
+                .position(|value| matches!(value, ComponentValue::SassNestingDeclaration(..)))
+            {
+                Some(index) => &value[..index],
+                None => &value[..],
+            };
+            if values.len() > 1 {
+                if let Some(keyword) = values.iter().find(|value| {
+                    matches!(
+                        value,
+                        ComponentValue::InterpolableIdent(InterpolableIdent::Literal(ident))
+                            if util::is_css_wide_keyword(&ident.name)
+                    )
+                }) {
+                    return Err(Error {
+                        kind: ErrorKind::CSSWideKeywordNotAlone,
+                        span: keyword.span().clone(),
+                    });
+                }
+            }
+        }
+
+        // https://drafts.csswg.org/css-fonts-4/#font-display-desc
+        if input.state.in_font_face && input.options.strict_font_face_descriptor {
+            if let InterpolableIdent::Literal(ident) = &name {
+                if ident.name.eq_ignore_ascii_case("font-display") {
+                    match &value[..] {
+                        [ComponentValue::InterpolableIdent(InterpolableIdent::Literal(keyword))]
+                            if ["auto", "block", "swap", "fallback", "optional"]
+                                .iter()
+                                .any(|allowed| keyword.name.eq_ignore_ascii_case(allowed)) => {}
+                        _ => {
+                            return Err(Error {
+                                kind: ErrorKind::InvalidFontFaceDescriptor,
+                                span: span.clone(),
+                            });
+                        }
+                    }
+                }
+            }
+        }
+
         Ok(Declaration {
             name,
             value,
@@ -165,16 +247,7 @@ impl<'cmt, 's: 'cmt> Parse<'cmt, 's> for QualifiedRule<'s> {
                 ..input.state
             })
             .parse::<SelectorList>()?;
-        let block = input.parse::<SimpleBlock>()?;
-        let span = Span {
-            start: selector_list.span.start,
-            end: block.span.end,
-        };
-        Ok(QualifiedRule {
-            selector: selector_list,
-            block,
-            span,
-        })
+        input.parse_qualified_rule_block(selector_list)
     }
 }
 
@@ -205,6 +278,52 @@ impl<'cmt, 's: 'cmt> Parse<'cmt, 's> for Stylesheet<'s> {
 }
 
 impl<'cmt, 's: 'cmt> Parser<'cmt, 's> {
+    fn parse_declaration_value(&mut self) -> PResult<Vec<ComponentValue<'s>>> {
+        let mut values = Vec::with_capacity(3);
+        loop {
+            match &peek!(self).token {
+                Token::RBrace(..)
+                | Token::RParen(..)
+                | Token::Semicolon(..)
+                | Token::Dedent(..)
+                | Token::Linebreak(..)
+                | Token::Exclamation(..)
+                | Token::Eof(..) => break,
+                _ => {
+                    let value = self.parse::<ComponentValue>()?;
+                    match &value {
+                        ComponentValue::SassNestingDeclaration(..)
+                            if matches!(self.syntax, Syntax::Scss | Syntax::Sass) =>
+                        {
+                            values.push(value);
+                            break;
+                        }
+                        ComponentValue::TokenWithSpan(TokenWithSpan {
+                            token: Token::BadStr(..),
+                            ..
+                        }) => {
+                            values.push(value);
+                            // declaration with bad string is invalid,
+                            // so rest of it is kept as raw tokens
+                            loop {
+                                match &peek!(self).token {
+                                    Token::RBrace(..)
+                                    | Token::Semicolon(..)
+                                    | Token::Dedent(..)
+                                    | Token::Eof(..) => break,
+                                    _ => values.push(ComponentValue::TokenWithSpan(bump!(self))),
+                                }
+                            }
+                            break;
+                        }
+                        _ => values.push(value),
+                    }
+                }
+            }
+        }
+        Ok(values)
+    }
+
     pub(super) fn parse_simple_block_with<F>(&mut self, f: F) -> PResult<SimpleBlock<'s>>
     where
         F: Fn(&mut Self) -> PResult<Vec<Statement<'s>>>,
@@ -256,14 +375,69 @@ impl<'cmt, 's: 'cmt> Parser<'cmt, 's> {
         }
     }
 
-    fn parse_statements(&mut self, is_top_level: bool) -> PResult<Vec<Statement<'s>>> {
+    fn parse_qualified_rule_block(
+        &mut self,
+        selector: SelectorList<'s>,
+    ) -> PResult<QualifiedRule<'s>> {
+        let block = self.parse::<SimpleBlock>()?;
+        let span = Span {
+            start: selector.span.start,
+            end: block.span.end,
+        };
+        Ok(QualifiedRule {
+            selector,
+            block,
+            span,
+        })
+    }
+
+    /// Parse qualified rule, Less guarded rule like `.a when (@x > 0) {}`,
+    /// or Less mixin call like `#ns > .mixin();` whose callee looks like a selector.
+    fn parse_qualified_rule_statement(&mut self) -> PResult<Statement<'s>> {
+        let selector = self
+            .with_state(ParserState {
+                qualified_rule_ctx: Some(QualifiedRuleContext::Selector),
+                ..self.state
+            })
+            .parse::<SelectorList>()?;
+        match &peek!(self).token {
+            // selector list stops before `when` keyword in Less
+            Token::Ident(ident)
+                if self.syntax == Syntax::Less && ident.name().eq_ignore_ascii_case("when") =>
+            {
+                self.parse_less_guarded_rule(selector)
+                    .map(Statement::LessGuardedRule)
+            }
+            Token::LParen(..)
+            | Token::Exclamation(..)
+            | Token::Semicolon(..)
+            | Token::RBrace(..)
+            | Token::Eof(..)
+                if self.syntax == Syntax::Less && less::is_less_mixin_callee(&selector) =>
+            {
+                self.parse_less_mixin_call_rest(less::convert_less_mixin_callee(selector))
+                    .map(Statement::LessMixinCall)
+            }
+            _ => self
+                .parse_qualified_rule_block(selector)
+                .map(Statement::QualifiedRule),
+        }
+    }
+
+    pub(super) fn parse_statements(&mut self, is_top_level: bool) -> PResult<Vec<Statement<'s>>> {
         let mut statements = Vec::with_capacity(1);
         loop {
             let mut is_block_element = false;
             match &peek!(self).token {
+                Token::Percentage(..) | Token::Ident(..) | Token::HashLBrace(..)
+                    if self.state.in_keyframes =>
+                {
+                    statements.push(Statement::KeyframeBlock(self.parse()?));
+                    is_block_element = true;
+                }
                 Token::Ident(..) | Token::HashLBrace(..) | Token::AtLBraceVar(..) => {
                     if is_top_level {
-                        statements.push(Statement::QualifiedRule(self.parse()?));
+                        statements.push(self.parse_qualified_rule_statement()?);
                         is_block_element = true;
                     } else {
                         match self.try_parse(Declaration::parse) {
@@ -275,8 +449,8 @@ impl<'cmt, 's: 'cmt> Parser<'cmt, 's> {
                                 statements.push(Statement::Declaration(declaration));
                             }
                             Err(e) => {
-                                if let Ok(rule) = self.parse() {
-                                    statements.push(Statement::QualifiedRule(rule));
+                                if let Ok(rule) = self.parse_qualified_rule_statement() {
+                                    statements.push(rule);
                                     is_block_element = true;
                                 } else {
                                     // using the error from parsing declaration for better error message
@@ -286,6 +460,11 @@ impl<'cmt, 's: 'cmt> Parser<'cmt, 's> {
                         }
                     }
                 }
+                Token::Dot(..) | Token::Hash(..) if self.syntax == Syntax::Less => {
+                    let statement = self.parse_qualified_rule_statement()?;
+                    is_block_element = !statement.is_less_mixin_call();
+                    statements.push(statement);
+                }
                 Token::Dot(..)
                 | Token::Hash(..)
                 | Token::Ampersand(..)
@@ -294,7 +473,7 @@ impl<'cmt, 's: 'cmt> Parser<'cmt, 's> {
                 | Token::ColonColon(..)
                 | Token::Asterisk(..)
                 | Token::Bar(..) => {
-                    statements.push(Statement::QualifiedRule(self.parse()?));
+                    statements.push(self.parse_qualified_rule_statement()?);
                     is_block_element = true;
                 }
                 Token::AtKeyword(at_keyword) => match self.syntax {
@@ -331,7 +510,7 @@ impl<'cmt, 's: 'cmt> Parser<'cmt, 's> {
                     }
                 },
                 Token::Percent(..) if matches!(self.syntax, Syntax::Scss | Syntax::Sass) => {
-                    statements.push(Statement::QualifiedRule(self.parse()?));
+                    statements.push(self.parse_qualified_rule_statement()?);
                     is_block_element = true;
                 }
                 Token::DollarVar(..) if matches!(self.syntax, Syntax::Scss | Syntax::Sass) => {
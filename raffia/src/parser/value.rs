@@ -5,8 +5,8 @@ use crate::{
     error::{Error, ErrorKind, PResult},
     expect, expect_without_ws_or_comments, peek,
     pos::{Span, Spanned},
-    tokenizer::{Token, TokenWithSpan},
-    util::{handle_escape, CowStr, LastOfNonEmpty},
+    tokenizer::{token, Token, TokenWithSpan},
+    util::{handle_escape, is_color_keyword, CowStr, LastOfNonEmpty},
     Parse, Syntax,
 };
 
@@ -73,8 +73,17 @@ impl<'cmt, 's: 'cmt> Parser<'cmt, 's> {
         match &token_with_span.token {
             Token::Ident(token) => {
                 if token.name().eq_ignore_ascii_case("url") {
-                    if let Ok(url) = self.try_parse(Url::parse) {
-                        return Ok(ComponentValue::Url(url));
+                    match self.try_parse(Url::parse) {
+                        Ok(url) => return Ok(ComponentValue::Url(url)),
+                        Err(
+                            error @ Error {
+                                kind: ErrorKind::InvalidUnquotedUrl,
+                                ..
+                            },
+                        ) => {
+                            return Err(error);
+                        }
+                        Err(..) => {}
                     }
                 }
                 let ident = self.parse::<InterpolableIdent>()?;
@@ -90,6 +99,68 @@ impl<'cmt, 's: 'cmt> Parser<'cmt, 's> {
                             {
                                 self.parse_src_url(ident).map(ComponentValue::Url)
                             }
+                            InterpolableIdent::Literal(ident)
+                                if self.state.in_font_face
+                                    && ident.name.eq_ignore_ascii_case("format") =>
+                            {
+                                if let Ok(format) = self
+                                    .try_parse(|parser| parser.parse_format_function(ident.clone()))
+                                {
+                                    Ok(ComponentValue::FormatFunction(format))
+                                } else {
+                                    self.parse_function(InterpolableIdent::Literal(ident))
+                                        .map(ComponentValue::Function)
+                                }
+                            }
+                            InterpolableIdent::Literal(ident)
+                                if ident.name.eq_ignore_ascii_case("attr") =>
+                            {
+                                if let Ok(attr) = self
+                                    .try_parse(|parser| parser.parse_attr_function(ident.clone()))
+                                {
+                                    Ok(ComponentValue::AttrFunction(Box::new(attr)))
+                                } else {
+                                    self.parse_function(InterpolableIdent::Literal(ident))
+                                        .map(ComponentValue::Function)
+                                }
+                            }
+                            InterpolableIdent::Literal(ident)
+                                if ident.name.eq_ignore_ascii_case("counter") =>
+                            {
+                                if let Ok(counter) = self.try_parse(|parser| {
+                                    parser.parse_counter_function(ident.clone())
+                                }) {
+                                    Ok(ComponentValue::CounterFunction(Box::new(counter)))
+                                } else {
+                                    self.parse_function(InterpolableIdent::Literal(ident))
+                                        .map(ComponentValue::Function)
+                                }
+                            }
+                            InterpolableIdent::Literal(ident)
+                                if ident.name.eq_ignore_ascii_case("counters") =>
+                            {
+                                if let Ok(counters) = self.try_parse(|parser| {
+                                    parser.parse_counters_function(ident.clone())
+                                }) {
+                                    Ok(ComponentValue::CountersFunction(Box::new(counters)))
+                                } else {
+                                    self.parse_function(InterpolableIdent::Literal(ident))
+                                        .map(ComponentValue::Function)
+                                }
+                            }
+                            InterpolableIdent::Literal(ident)
+                                if self.options.tailwind
+                                    && ident.name.eq_ignore_ascii_case("theme") =>
+                            {
+                                if let Ok(theme) = self
+                                    .try_parse(|parser| parser.parse_theme_function(ident.clone()))
+                                {
+                                    Ok(ComponentValue::ThemeFunction(theme))
+                                } else {
+                                    self.parse_function(InterpolableIdent::Literal(ident))
+                                        .map(ComponentValue::Function)
+                                }
+                            }
                             ident => self.parse_function(ident).map(ComponentValue::Function),
                         };
                     }
@@ -134,6 +205,15 @@ impl<'cmt, 's: 'cmt> Parser<'cmt, 's> {
                             )),
                         }
                     }
+                    InterpolableIdent::Literal(ident)
+                        if !self.state.in_name_valued_property && is_color_keyword(&ident.name) =>
+                    {
+                        let span = ident.span.clone();
+                        Ok(ComponentValue::ColorKeyword(ColorKeyword {
+                            name: ident,
+                            span,
+                        }))
+                    }
                     _ => Ok(ComponentValue::InterpolableIdent(ident)),
                 }
             }
@@ -146,6 +226,19 @@ impl<'cmt, 's: 'cmt> Parser<'cmt, 's> {
                 .parse()
                 .map(InterpolableStr::Literal)
                 .map(ComponentValue::InterpolableStr),
+            Token::BadStr(..) => {
+                let token_with_span = bump!(self);
+                let error = Error {
+                    kind: ErrorKind::UnterminatedString,
+                    span: token_with_span.span.clone(),
+                };
+                if self.options.allow_bad_string {
+                    self.recoverable_errors.push(error);
+                    Ok(ComponentValue::TokenWithSpan(token_with_span))
+                } else {
+                    Err(error)
+                }
+            }
             Token::LBracket(..) => self.parse().map(ComponentValue::BracketBlock),
             Token::DollarVar(..) if matches!(self.syntax, Syntax::Scss | Syntax::Sass) => {
                 self.parse().map(ComponentValue::SassVariable)
@@ -153,8 +246,10 @@ impl<'cmt, 's: 'cmt> Parser<'cmt, 's> {
             Token::LParen(..) if matches!(self.syntax, Syntax::Scss | Syntax::Sass) => {
                 if let Ok(expr) = self.try_parse(SassParenthesizedExpression::parse) {
                     Ok(ComponentValue::SassParenthesizedExpression(expr))
+                } else if let Ok(map) = self.try_parse(SassMap::parse) {
+                    Ok(ComponentValue::SassMap(map))
                 } else {
-                    self.parse().map(ComponentValue::SassMap)
+                    self.parse().map(ComponentValue::SassList)
                 }
             }
             Token::HashLBrace(..) if matches!(self.syntax, Syntax::Scss | Syntax::Sass) => self
@@ -230,6 +325,86 @@ impl<'cmt, 's: 'cmt> Parser<'cmt, 's> {
         Ok(ComponentValues { values, span })
     }
 
+    // https://drafts.csswg.org/css-values-5/#attr-notation
+    fn parse_attr_function(&mut self, name: Ident<'s>) -> PResult<AttrFunction<'s>> {
+        // caller of `parse_attr_function` should make sure there're no whitespaces before paren
+        expect!(self, LParen);
+        let attr_name = self.parse::<WqName>()?;
+        let type_or_unit = if let Token::Ident(..) = &peek!(self).token {
+            Some(self.parse::<Ident>()?)
+        } else {
+            None
+        };
+        let fallback = if eat!(self, Comma).is_some() {
+            Some(self.parse_component_values(
+                /* allow_comma */ true, /* allow_semicolon */ false,
+            )?)
+        } else {
+            None
+        };
+        let end = expect!(self, RParen).1.end;
+        let span = Span {
+            start: name.span.start,
+            end,
+        };
+        Ok(AttrFunction {
+            name,
+            attr_name,
+            type_or_unit,
+            fallback,
+            span,
+        })
+    }
+
+    // https://www.w3.org/TR/css-lists-3/#counter-functions
+    fn parse_counter_function(&mut self, name: Ident<'s>) -> PResult<CounterFunction<'s>> {
+        // caller of `parse_counter_function` should make sure there're no whitespaces before paren
+        expect!(self, LParen);
+        let counter_name = self.parse::<InterpolableIdent>()?;
+        let style = if eat!(self, Comma).is_some() {
+            Some(self.parse::<CounterStyle>()?)
+        } else {
+            None
+        };
+        let end = expect!(self, RParen).1.end;
+        let span = Span {
+            start: name.span.start,
+            end,
+        };
+        Ok(CounterFunction {
+            name,
+            counter_name,
+            style,
+            span,
+        })
+    }
+
+    // https://www.w3.org/TR/css-lists-3/#counter-functions
+    fn parse_counters_function(&mut self, name: Ident<'s>) -> PResult<CountersFunction<'s>> {
+        // caller of `parse_counters_function` should make sure there're no whitespaces before paren
+        expect!(self, LParen);
+        let counter_name = self.parse::<InterpolableIdent>()?;
+        expect!(self, Comma);
+        let separator = self.parse::<InterpolableStr>()?;
+        let style = if eat!(self, Comma).is_some() {
+            Some(self.parse::<CounterStyle>()?)
+        } else {
+            None
+        };
+        let end = expect!(self, RParen).1.end;
+        let span = Span {
+            start: name.span.start,
+            end,
+        };
+        Ok(CountersFunction {
+            name,
+            counter_name,
+            separator,
+            style,
+            span,
+        })
+    }
+
     pub(super) fn parse_dashed_ident(&mut self) -> PResult<InterpolableIdent<'s>> {
         let ident = self.parse()?;
         match &ident {
@@ -244,6 +419,101 @@ impl<'cmt, 's: 'cmt> Parser<'cmt, 's> {
         Ok(ident)
     }
 
+    // https://www.w3.org/TR/css-fonts-4/#font-face-src-parsing
+    fn parse_format_function(&mut self, name: Ident<'s>) -> PResult<FormatFunction<'s>> {
+        // caller of `parse_format_function` should make sure there're no whitespaces before paren
+        expect!(self, LParen);
+        let mut formats = vec![self.parse::<FontFormat>()?];
+        while eat!(self, Comma).is_some() {
+            formats.push(self.parse()?);
+        }
+        let end = expect!(self, RParen).1.end;
+        let span = Span {
+            start: name.span.start,
+            end,
+        };
+        Ok(FormatFunction {
+            name,
+            formats,
+            span,
+        })
+    }
+
+    // https://tailwindcss.com/docs/functions-and-directives#theme
+    fn parse_theme_function(&mut self, name: Ident<'s>) -> PResult<ThemeFunction<'s>> {
+        // caller of `parse_theme_function` should make sure there're no whitespaces before paren
+        expect!(self, LParen);
+        let path = if let Token::Str(..) = &peek!(self).token {
+            vec![ThemeKeySegment::Str(self.parse()?)]
+        } else {
+            let first = self.parse::<Ident>()?;
+            let mut end = first.span.end;
+            let mut path = vec![ThemeKeySegment::Ident(first)];
+            loop {
+                match peek!(self) {
+                    TokenWithSpan {
+                        token: Token::Dot(..),
+                        span,
+                    } if span.start == end => {
+                        let dot_end = bump!(self).span.end;
+                        let ident = self.parse::<Ident>()?;
+                        if ident.span.start != dot_end {
+                            return Err(Error {
+                                kind: ErrorKind::UnexpectedWhitespace,
+                                span: Span {
+                                    start: dot_end,
+                                    end: ident.span.start,
+                                },
+                            });
+                        }
+                        end = ident.span.end;
+                        path.push(ThemeKeySegment::Ident(ident));
+                    }
+                    // something like `.500` is scanned as a number token
+                    TokenWithSpan {
+                        token: Token::Number(number),
+                        span,
+                    } if span.start == end && number.raw.starts_with('.') => {
+                        let (number, span) = expect!(self, Number);
+                        end = span.end;
+                        let number = self.number_from_token(
+                            token::Number {
+                                raw: unsafe { number.raw.get_unchecked(1..) },
+                                sign_explicit: false,
+                            },
+                            Span {
+                                start: span.start + 1,
+                                end: span.end,
+                            },
+                        )?;
+                        path.push(ThemeKeySegment::Number(number));
+                    }
+                    _ => break,
+                }
+            }
+            path
+        };
+
+        let fallback = if eat!(self, Comma).is_some() {
+            Some(self.parse_component_values(
+                /* allow_comma */ true, /* allow_semicolon */ false,
+            )?)
+        } else {
+            None
+        };
+        let end = expect!(self, RParen).1.end;
+        let span = Span {
+            start: name.span.start,
+            end,
+        };
+        Ok(ThemeFunction {
+            name,
+            path,
+            fallback,
+            span,
+        })
+    }
+
     pub(super) fn parse_function(&mut self, name: InterpolableIdent<'s>) -> PResult<Function<'s>> {
         expect!(self, LParen);
         let values = if let Token::RParen(..) = &peek!(self).token {
@@ -321,6 +591,20 @@ impl<'cmt, 's: 'cmt> Parser<'cmt, 's> {
         })
     }
 
+    /// Numbers which overflow `f32` are valid in CSS and clamped,
+    /// so only a recoverable error is recorded for them.
+    fn number_from_token(&mut self, token: token::Number<'s>, span: Span) -> PResult<Number<'s>> {
+        let mut number = Number::try_from_token(token, span)?;
+        if number.value.is_infinite() {
+            self.recoverable_errors.push(Error {
+                kind: ErrorKind::NumberOverflow,
+                span: number.span.clone(),
+            });
+            number.value = f32::MAX.copysign(number.value);
+        }
+        Ok(number)
+    }
+
     pub(super) fn parse_ratio(&mut self, numerator: Number<'s>) -> PResult<Ratio<'s>> {
         expect!(self, Solidus);
         let denominator = self.parse::<Number>()?;
@@ -577,6 +861,8 @@ impl<'cmt, 's: 'cmt> Parse<'cmt, 's> for ComponentValue<'s> {
     fn parse(input: &mut Parser<'cmt, 's>) -> PResult<Self> {
         if matches!(input.syntax, Syntax::Scss | Syntax::Sass) {
             input.parse_sass_bin_expr()
+        } else if input.syntax == Syntax::Less {
+            input.parse_less_operation()
         } else {
             input.parse_component_value_atom()
         }
@@ -590,6 +876,25 @@ impl<'cmt, 's: 'cmt> Parse<'cmt, 's> for ComponentValues<'s> {
     }
 }
 
+// https://www.w3.org/TR/css-counter-styles-3/#typedef-counter-style
+impl<'cmt, 's: 'cmt> Parse<'cmt, 's> for CounterStyle<'s> {
+    fn parse(input: &mut Parser<'cmt, 's>) -> PResult<Self> {
+        let name = input.parse::<InterpolableIdent>()?;
+        match (&name, peek!(input)) {
+            (
+                InterpolableIdent::Literal(ident),
+                TokenWithSpan {
+                    token: Token::LParen(..),
+                    span,
+                },
+            ) if ident.name.eq_ignore_ascii_case("symbols") && span.start == ident.span.end => {
+                input.parse_function(name).map(CounterStyle::Symbols)
+            }
+            _ => Ok(CounterStyle::Name(name)),
+        }
+    }
+}
+
 impl<'cmt, 's: 'cmt> Parse<'cmt, 's> for Delimiter {
     fn parse(input: &mut Parser<'cmt, 's>) -> PResult<Self> {
         use crate::tokenizer::token::*;
@@ -631,7 +936,7 @@ impl<'cmt, 's: 'cmt> Parse<'cmt, 's> for Dimension<'s> {
             start: span.start + dimension_token.value.raw.len(),
             end: span.end,
         };
-        let value = Number::try_from_token(dimension_token.value, value_span)?;
+        let value = input.number_from_token(dimension_token.value, value_span)?;
         let unit = Ident::from_token(dimension_token.unit, unit_span);
         let unit_name = &unit.name;
         if unit_name.eq_ignore_ascii_case("px")
@@ -702,6 +1007,28 @@ impl<'cmt, 's: 'cmt> Parse<'cmt, 's> for Dimension<'s> {
     }
 }
 
+impl<'cmt, 's: 'cmt> Parse<'cmt, 's> for FontFormat<'s> {
+    fn parse(input: &mut Parser<'cmt, 's>) -> PResult<Self> {
+        match &peek!(input).token {
+            Token::Str(..) | Token::StrTemplate(..) => input.parse().map(FontFormat::Str),
+            _ => {
+                // legacy syntax like `format(woff2 supports variations)`
+                let first = input.parse::<InterpolableIdent>()?;
+                let mut span = first.span().clone();
+
+                let mut idents = vec![first];
+                while let Token::Ident(..) | Token::HashLBrace(..) | Token::AtLBraceVar(..) =
+                    &peek!(input).token
+                {
+                    idents.push(input.parse()?);
+                }
+                span.end = idents.last_of_non_empty().span().end;
+                Ok(FontFormat::Unquoted(UnquotedFontFormat { idents, span }))
+            }
+        }
+    }
+}
+
 impl<'cmt, 's: 'cmt> Parse<'cmt, 's> for HexColor<'s> {
     fn parse(input: &mut Parser<'cmt, 's>) -> PResult<Self> {
         let (token, span) = expect!(input, Hash);
@@ -771,18 +1098,7 @@ impl<'cmt, 's: 'cmt> Parse<'cmt, 's> for InterpolableStr<'s> {
 impl<'cmt, 's: 'cmt> Parse<'cmt, 's> for Number<'s> {
     fn parse(input: &mut Parser<'cmt, 's>) -> PResult<Self> {
         let (number, span) = expect!(input, Number);
-        number
-            .raw
-            .parse()
-            .map_err(|_| Error {
-                kind: ErrorKind::InvalidNumber,
-                span: span.clone(),
-            })
-            .map(|value| Self {
-                value,
-                raw: number.raw,
-                span,
-            })
+        input.number_from_token(number, span)
     }
 }
 
@@ -790,7 +1106,7 @@ impl<'cmt, 's: 'cmt> Parse<'cmt, 's> for Percentage<'s> {
     fn parse(input: &mut Parser<'cmt, 's>) -> PResult<Self> {
         let (token, span) = expect!(input, Percentage);
         Ok(Percentage {
-            value: Number::try_from_token(
+            value: input.number_from_token(
                 token.value,
                 Span {
                     start: span.start,
@@ -855,34 +1171,44 @@ impl<'cmt, 's: 'cmt> Parse<'cmt, 's> for Url<'s> {
                 modifiers,
                 span,
             })
-        } else if let Ok(value) = input.try_parse(|parser| parser.parse::<UrlRaw>()) {
-            let span = Span {
-                start: prefix_span.start,
-                end: value.span.end + 1, // `)` is consumed, but span excludes it
-            };
-            Ok(Url {
-                name: Ident::from_token(prefix, prefix_span),
-                value: Some(UrlValue::Raw(value)),
-                modifiers: vec![],
-                span,
-            })
-        } else if matches!(input.syntax, Syntax::Scss | Syntax::Sass) {
-            let value = input.parse::<SassInterpolatedUrl>()?;
-            let span = Span {
-                start: prefix_span.start,
-                end: value.span.end + 1, // `)` is consumed, but span excludes it
-            };
-            Ok(Url {
-                name: Ident::from_token(prefix, prefix_span),
-                value: Some(UrlValue::SassInterpolated(value)),
-                modifiers: vec![],
-                span,
-            })
         } else {
-            Err(Error {
-                kind: ErrorKind::ExpectUrl,
-                span: bump!(input).span().clone(),
-            })
+            match input.try_parse(|parser| parser.parse::<UrlRaw>()) {
+                Ok(value) => {
+                    let span = Span {
+                        start: prefix_span.start,
+                        end: value.span.end + 1, // `)` is consumed, but span excludes it
+                    };
+                    Ok(Url {
+                        name: Ident::from_token(prefix, prefix_span),
+                        value: Some(UrlValue::Raw(value)),
+                        modifiers: vec![],
+                        span,
+                    })
+                }
+                Err(..) if matches!(input.syntax, Syntax::Scss | Syntax::Sass) => {
+                    let value = input.parse::<SassInterpolatedUrl>()?;
+                    let span = Span {
+                        start: prefix_span.start,
+                        end: value.span.end + 1, // `)` is consumed, but span excludes it
+                    };
+                    Ok(Url {
+                        name: Ident::from_token(prefix, prefix_span),
+                        value: Some(UrlValue::SassInterpolated(value)),
+                        modifiers: vec![],
+                        span,
+                    })
+                }
+                Err(
+                    error @ Error {
+                        kind: ErrorKind::InvalidUnquotedUrl,
+                        ..
+                    },
+                ) => Err(error),
+                Err(..) => Err(Error {
+                    kind: ErrorKind::ExpectUrl,
+                    span: bump!(input).span().clone(),
+                }),
+            }
         }
     }
 }
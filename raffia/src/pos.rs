@@ -15,3 +15,101 @@ pub struct Span {
 pub trait Spanned {
     fn span(&self) -> &Span;
 }
+
+/// Index of line starts in source code,
+/// which converts offset to line and column.
+///
+/// Both line and column are 0-based.
+/// Like CSS, `\n`, `\r\n`, `\r` and `\f` are all treated as newlines.
+///
+/// ```rust
+/// use raffia::LineIndex;
+///
+/// let index = LineIndex::new("a {\n  color: red;\n}");
+/// assert_eq!(index.line_col(0), (0, 0));
+/// assert_eq!(index.line_col(8), (1, 4));
+/// assert_eq!(index.line_col(18), (2, 0));
+///
+/// let index = LineIndex::new("a {\r\n  color: red;\r  width: 0;\x0c}");
+/// assert_eq!(index.line_col(9), (1, 4));
+/// assert_eq!(index.line_col(21), (2, 2));
+/// assert_eq!(index.line_col(31), (3, 0));
+/// ```
+#[derive(Clone, Debug)]
+pub struct LineIndex<'s> {
+    source: &'s str,
+    line_starts: Vec<usize>,
+}
+
+impl<'s> LineIndex<'s> {
+    /// Build line index of the given source code.
+    pub fn new(source: &'s str) -> Self {
+        let bytes = source.as_bytes();
+        let line_starts = std::iter::once(0)
+            .chain(
+                bytes
+                    .iter()
+                    .enumerate()
+                    .filter(|(i, byte)| match byte {
+                        b'\n' | b'\x0c' => true,
+                        b'\r' => bytes.get(i + 1) != Some(&b'\n'),
+                        _ => false,
+                    })
+                    .map(|(i, _)| i + 1),
+            )
+            .collect();
+        LineIndex {
+            source,
+            line_starts,
+        }
+    }
+
+    /// Get 0-based line of the given offset.
+    pub fn line(&self, offset: usize) -> usize {
+        match self.line_starts.binary_search(&offset) {
+            Ok(line) => line,
+            Err(line) => line - 1,
+        }
+    }
+
+    /// Get 0-based line and column of the given offset.
+    /// Column counts by bytes.
+    pub fn line_col(&self, offset: usize) -> (usize, usize) {
+        let line = self.line(offset);
+        (line, offset - self.line_starts[line])
+    }
+
+    /// Get 0-based column of the given offset which counts by UTF-16 code units,
+    /// as Language Server Protocol uses by default.
+    ///
+    /// Characters outside Basic Multilingual Plane, like emoji, take two code units.
+    /// Offset beyond the end of source is clamped to the end,
+    /// and offset inside a character is moved back to the start of that character.
+    ///
+    /// ```rust
+    /// use raffia::LineIndex;
+    ///
+    /// let source = "a {}\n/* 中文 😀 */ b {}";
+    /// let index = LineIndex::new(source);
+    /// let offset = source.find('b').unwrap();
+    /// assert_eq!(index.line_col(offset), (1, 18));
+    /// assert_eq!(index.utf16_column(offset), 12);
+    /// assert_eq!(index.utf16_column(source.find('文').unwrap() + 1), 4);
+    /// assert_eq!(index.utf16_column(source.len() + 10), 16);
+    ///
+    /// let source = "a {}\r\n😀 b {}";
+    /// let index = LineIndex::new(source);
+    /// assert_eq!(index.utf16_column(source.find('b').unwrap()), 3);
+    /// ```
+    pub fn utf16_column(&self, offset: usize) -> u32 {
+        let mut offset = offset.min(self.source.len());
+        while !self.source.is_char_boundary(offset) {
+            offset -= 1;
+        }
+        let line_start = self.line_starts[self.line(offset)];
+        self.source[line_start..offset]
+            .chars()
+            .map(|c| c.len_utf16() as u32)
+            .sum()
+    }
+}
@@ -6,8 +6,10 @@ impl TryFrom<token::Number<'_>> for i32 {
 
     fn try_from(token::Number { raw, .. }: token::Number) -> Result<Self, ErrorKind> {
         let value = raw.parse::<f32>().map_err(|_| ErrorKind::InvalidNumber)?;
-        if value.fract() == 0.0 {
-            // SAFETY: f32 parsed from source text will never be NaN or infinity.
+        if !value.is_finite() || value < i32::MIN as f32 || value >= i32::MAX as f32 {
+            Err(ErrorKind::NumberOverflow)
+        } else if value.fract() == 0.0 {
+            // SAFETY: value is finite and within range of `i32`.
             unsafe { Ok(value.to_int_unchecked()) }
         } else {
             Err(ErrorKind::ExpectInteger)
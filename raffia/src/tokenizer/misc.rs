@@ -1,4 +1,7 @@
-use super::{token::Ident, TokenWithSpan};
+use super::{
+    token::{Hash, Ident},
+    TokenWithSpan,
+};
 use crate::{
     util::{handle_escape, CowStr},
     SpanIgnoredEq,
@@ -15,6 +18,31 @@ impl<'s> Ident<'s> {
     }
 }
 
+impl Hash<'_> {
+    /// Check if this hash can be a hex color,
+    /// which means it consists of 3, 4, 6 or 8 hex digits.
+    ///
+    /// ```rust
+    /// use raffia::{token::Token, tokenize, Syntax};
+    ///
+    /// let valid = tokenize("#fff #FFFA #12 #00ff0080 #zzz", Syntax::Css)
+    ///     .filter_map(|token| match token.unwrap().token {
+    ///         Token::Hash(hash) => Some(hash.is_valid_color()),
+    ///         _ => None,
+    ///     })
+    ///     .collect::<Vec<_>>();
+    /// assert_eq!(valid, [true, true, false, true, false]);
+    /// ```
+    pub fn is_valid_color(&self) -> bool {
+        let value = if self.escaped {
+            handle_escape(self.raw)
+        } else {
+            CowStr::from(self.raw)
+        };
+        matches!(value.len(), 3 | 4 | 6 | 8) && value.chars().all(|c| c.is_ascii_hexdigit())
+    }
+}
+
 impl SpanIgnoredEq for TokenWithSpan<'_> {
     #[inline]
     fn span_ignored_eq(&self, other: &Self) -> bool {
@@ -1,8 +1,9 @@
 use crate::{
-    config::Syntax,
+    config::{IndentUnit, Syntax},
     error::{Error, ErrorKind, PResult},
     pos::Span,
 };
+use smallvec::SmallVec;
 use std::{cmp::Ordering, iter::Peekable, str::CharIndices};
 pub(crate) use symbol::TokenSymbol;
 use token::*;
@@ -17,11 +18,37 @@ pub mod token;
 pub(crate) struct TokenizerState<'s> {
     chars: Peekable<CharIndices<'s>>,
     indent_size: u16,
+    /// Tokens which have been scanned by [`Tokenizer::peek_nth`] but not consumed yet.
+    lookahead: SmallVec<[LookaheadToken<'s>; 1]>,
+}
+
+/// Token buffered by [`Tokenizer::peek_nth`],
+/// along with the state before it was scanned so that it can be scanned again.
+#[derive(Clone)]
+struct LookaheadToken<'s> {
+    token: TokenWithSpan<'s>,
+    chars: Peekable<CharIndices<'s>>,
+    indent_size: u16,
+    comments_count: usize,
+    errors_count: usize,
+}
+
+/// Snapshot of tokenizer, created by [`Tokenizer::checkpoint`].
+#[derive(Clone)]
+pub struct TokenizerCheckpoint<'s> {
+    state: TokenizerState<'s>,
+    comments_count: usize,
+    errors_count: usize,
 }
 
 pub struct Tokenizer<'cmt, 's: 'cmt> {
     source: &'s str,
-    syntax: Syntax,
+    pub(crate) syntax: Syntax,
+    pub(crate) indent_unit: Option<IndentUnit>,
+    pub(crate) strict_block_comment: bool,
+    pub(crate) allow_invalid_escape: bool,
+    /// Recoverable errors which will be taken away by parser.
+    pub(crate) errors: Vec<Error>,
     pub(crate) comments: Option<&'cmt mut Vec<Comment<'s>>>,
     pub(crate) state: TokenizerState<'s>,
 }
@@ -32,32 +59,194 @@ impl<'cmt, 's: 'cmt> Tokenizer<'cmt, 's> {
         syntax: Syntax,
         comments: Option<&'cmt mut Vec<Comment<'s>>>,
     ) -> Self {
+        let mut chars = source.char_indices().peekable();
+        // leading BOM isn't part of source code, but spans are still offsets in original source
+        chars.next_if(|(_, c)| *c == '\u{feff}');
         Self {
             source,
             syntax,
+            indent_unit: None,
+            strict_block_comment: false,
+            allow_invalid_escape: false,
+            errors: vec![],
             comments,
             state: TokenizerState {
-                chars: source.char_indices().peekable(),
+                chars,
                 indent_size: 0,
+                lookahead: SmallVec::new(),
             },
         }
     }
 
     #[inline]
     pub fn bump(&mut self) -> PResult<TokenWithSpan<'s>> {
-        if let Some(indent) = self.skip_ws_or_comment() {
+        if !self.state.lookahead.is_empty() {
+            Ok(self.state.lookahead.remove(0).token)
+        } else {
+            self.scan()
+        }
+    }
+
+    /// Look ahead the `n`-th token without consuming it,
+    /// where `peek_nth(0)` is the token that will be returned by next [`bump`](Tokenizer::bump).
+    ///
+    /// Peeked tokens are buffered, so peeking them again won't scan them
+    /// or collect their comments again.
+    ///
+    /// ```rust
+    /// use raffia::{token::Token, Syntax, Tokenizer};
+    ///
+    /// let mut comments = vec![];
+    /// let mut tokenizer = Tokenizer::new("a /* b */ > c", Syntax::Css, Some(&mut comments));
+    /// assert!(matches!(tokenizer.peek_nth(2).unwrap().token, Token::Ident(..)));
+    /// assert!(matches!(tokenizer.peek_nth(1).unwrap().token, Token::GreaterThan(..)));
+    /// assert!(matches!(tokenizer.peek_nth(0).unwrap().token, Token::Ident(..)));
+    /// assert!(matches!(tokenizer.bump().unwrap().token, Token::Ident(..)));
+    /// assert!(matches!(tokenizer.peek_nth(0).unwrap().token, Token::GreaterThan(..)));
+    /// assert!(matches!(tokenizer.peek_nth(0).unwrap().token, Token::GreaterThan(..)));
+    /// drop(tokenizer);
+    /// assert_eq!(comments.len(), 1);
+    /// ```
+    pub fn peek_nth(&mut self, n: usize) -> PResult<&TokenWithSpan<'s>> {
+        while self.state.lookahead.len() <= n {
+            let chars = self.state.chars.clone();
+            let indent_size = self.state.indent_size;
+            let comments_count = self.comments.as_ref().map_or(0, |comments| comments.len());
+            let errors_count = self.errors.len();
+            let token = self.scan()?;
+            self.state.lookahead.push(LookaheadToken {
+                token,
+                chars,
+                indent_size,
+                comments_count,
+                errors_count,
+            });
+        }
+        Ok(&self.state.lookahead[n].token)
+    }
+
+    /// Retrieve the token returned by last successful call of [`peek_nth(0)`](Tokenizer::peek_nth)
+    /// without mutably borrowing tokenizer.
+    #[inline]
+    pub(crate) fn peeked(&self) -> &TokenWithSpan<'s> {
+        &self.state.lookahead[0].token
+    }
+
+    #[inline]
+    pub(crate) fn has_lookahead(&self) -> bool {
+        !self.state.lookahead.is_empty()
+    }
+
+    /// Discard tokens buffered by [`peek_nth`](Tokenizer::peek_nth),
+    /// so they will be scanned again from where the first one started.
+    pub(crate) fn discard_lookahead(&mut self) {
+        if !self.state.lookahead.is_empty() {
+            let lookahead = self.state.lookahead.remove(0);
+            self.state.chars = lookahead.chars;
+            self.state.indent_size = lookahead.indent_size;
+            self.state.lookahead.clear();
+            self.errors.truncate(lookahead.errors_count);
+            if let Some(comments) = &mut self.comments {
+                comments.truncate(lookahead.comments_count);
+            }
+        }
+    }
+
+    #[inline]
+    fn scan(&mut self) -> PResult<TokenWithSpan<'s>> {
+        if let Some(indent) = self.skip_ws_or_comment()? {
             Ok(indent)
         } else {
             self.next()
         }
     }
 
+    /// Consume next token without skipping whitespaces and comments before it.
+    ///
+    /// Tokens buffered by [`peek_nth`](Tokenizer::peek_nth) are discarded and scanned again.
+    ///
+    /// ```rust
+    /// use raffia::{token::Token, Syntax, Tokenizer};
+    ///
+    /// let mut comments = vec![];
+    /// let mut tokenizer = Tokenizer::new("a/* b */c", Syntax::Css, Some(&mut comments));
+    /// tokenizer.bump().unwrap();
+    /// assert!(matches!(tokenizer.peek_nth(0).unwrap().token, Token::Ident(..)));
+    /// assert!(matches!(
+    ///     tokenizer.bump_without_ws_or_comments().unwrap().token,
+    ///     Token::Solidus(..)
+    /// ));
+    /// drop(tokenizer);
+    /// assert!(comments.is_empty());
+    /// ```
     #[inline]
     pub fn bump_without_ws_or_comments(&mut self) -> PResult<TokenWithSpan<'s>> {
+        // buffered tokens may have skipped whitespaces or comments before them,
+        // so discard them and scan again from where the first one started
+        self.discard_lookahead();
         self.next()
     }
 
+    /// Take a snapshot of current state, which can be restored by [`restore`](Tokenizer::restore).
+    ///
+    /// ```rust
+    /// use raffia::{token::Token, Syntax, Tokenizer};
+    ///
+    /// let mut comments = vec![];
+    /// let mut tokenizer = Tokenizer::new("a /* b */ c", Syntax::Css, Some(&mut comments));
+    /// tokenizer.bump().unwrap();
+    /// let checkpoint = tokenizer.checkpoint();
+    /// assert_eq!(tokenizer.bump().unwrap().span.start, 10);
+    /// tokenizer.restore(checkpoint);
+    /// assert_eq!(tokenizer.bump().unwrap().span.start, 10);
+    /// assert!(matches!(tokenizer.bump().unwrap().token, Token::Eof(..)));
+    /// drop(tokenizer);
+    /// assert_eq!(comments.len(), 1);
+    /// ```
+    pub fn checkpoint(&self) -> TokenizerCheckpoint<'s> {
+        TokenizerCheckpoint {
+            state: self.state.clone(),
+            comments_count: self.comments.as_ref().map_or(0, |comments| comments.len()),
+            errors_count: self.errors.len(),
+        }
+    }
+
+    /// Roll back to the given snapshot.
+    /// Comments collected after that snapshot was taken will be removed.
+    pub fn restore(&mut self, checkpoint: TokenizerCheckpoint<'s>) {
+        self.state = checkpoint.state;
+        self.errors.truncate(checkpoint.errors_count);
+        if let Some(comments) = &mut self.comments {
+            comments.truncate(checkpoint.comments_count);
+        }
+    }
+
+    /// Offset where the next token will be scanned from.
+    ///
+    /// Tokens buffered by [`peek_nth`](Tokenizer::peek_nth) are treated as not scanned yet.
+    ///
+    /// ```rust
+    /// use raffia::{Syntax, Tokenizer};
+    ///
+    /// let mut tokenizer = Tokenizer::new("a  b c", Syntax::Css, None);
+    /// tokenizer.bump().unwrap();
+    /// assert_eq!(tokenizer.current_offset(), 1);
+    /// tokenizer.peek_nth(1).unwrap();
+    /// assert_eq!(tokenizer.current_offset(), 1);
+    /// ```
     pub fn current_offset(&mut self) -> usize {
+        match self.state.lookahead.first() {
+            Some(lookahead) => lookahead
+                .chars
+                .clone()
+                .peek()
+                .map_or(self.source.len(), |(offset, _)| *offset),
+            None => self.char_offset(),
+        }
+    }
+
+    #[inline]
+    fn char_offset(&mut self) -> usize {
         if let Some((offset, _)) = self.state.chars.peek() {
             *offset
         } else {
@@ -75,7 +264,7 @@ impl<'cmt, 's: 'cmt> Tokenizer<'cmt, 's> {
 
     #[cold]
     fn build_eof_error(&mut self) -> Error {
-        let offset = self.current_offset();
+        let offset = self.char_offset();
         Error {
             kind: ErrorKind::UnexpectedEof,
             span: Span {
@@ -120,7 +309,7 @@ impl<'cmt, 's: 'cmt> Tokenizer<'cmt, 's> {
             (Some((_, '\'' | '"')), ..) => self.scan_string_or_template(),
             (Some((_, '@')), Some((_, c))) if is_start_of_ident(c) => self.scan_at_keyword(),
             (Some((start, '-')), Some((_, '-'))) => {
-                if matches!(chars.peek(), Some((_, '>'))) {
+                if self.syntax == Syntax::Css && matches!(chars.peek(), Some((_, '>'))) {
                     self.scan_cdc(start)
                 } else {
                     self.scan_ident()
@@ -149,7 +338,7 @@ impl<'cmt, 's: 'cmt> Tokenizer<'cmt, 's> {
             }
             (Some(..), ..) => self.scan_punc(),
             (None, ..) => {
-                let offset = self.current_offset();
+                let offset = self.char_offset();
                 Ok(TokenWithSpan {
                     token: Token::Eof(Eof {}),
                     span: Span {
@@ -161,13 +350,13 @@ impl<'cmt, 's: 'cmt> Tokenizer<'cmt, 's> {
         }
     }
 
-    fn skip_ws_or_comment(&mut self) -> Option<TokenWithSpan<'s>> {
+    fn skip_ws_or_comment(&mut self) -> PResult<Option<TokenWithSpan<'s>>> {
         let mut indent = None;
         loop {
             match self.state.chars.peek() {
                 Some((_, c)) if c.is_ascii_whitespace() => {
                     if self.syntax == Syntax::Sass {
-                        indent = self.scan_indent();
+                        indent = self.scan_indent()?;
                     } else {
                         self.skip_ws();
                     }
@@ -176,7 +365,7 @@ impl<'cmt, 's: 'cmt> Tokenizer<'cmt, 's> {
                     let mut chars = self.state.chars.clone();
                     chars.next();
                     match chars.next() {
-                        Some((_, '*')) => self.scan_block_comment(),
+                        Some((_, '*')) => self.scan_block_comment()?,
                         Some((_, '/')) if self.syntax != Syntax::Css => self.scan_line_comment(),
                         _ => break,
                     }
@@ -184,7 +373,7 @@ impl<'cmt, 's: 'cmt> Tokenizer<'cmt, 's> {
                 _ => break,
             }
         }
-        indent
+        Ok(indent)
     }
 
     fn skip_ws(&mut self) {
@@ -197,55 +386,77 @@ impl<'cmt, 's: 'cmt> Tokenizer<'cmt, 's> {
         }
     }
 
-    fn scan_indent(&mut self) -> Option<TokenWithSpan<'s>> {
+    fn scan_indent(&mut self) -> PResult<Option<TokenWithSpan<'s>>> {
         debug_assert_eq!(self.syntax, Syntax::Sass);
         let mut start = None;
         while let Some((i, c)) = self.state.chars.peek() {
             if c.is_ascii_whitespace() {
-                let (i, c) = self.state.chars.next()?;
+                let (i, c) = match self.state.chars.next() {
+                    Some(item) => item,
+                    None => return Ok(None),
+                };
                 if c == '\n' || c == '\r' && matches!(self.state.chars.peek(), Some((_, '\n'))) {
                     start = Some(i + 1);
                 }
             } else {
-                return start.map(|start| {
-                    let end = *i;
-                    let len = (end - start) as u16;
-                    let span = Span { start, end };
-                    match len.cmp(&self.state.indent_size) {
-                        Ordering::Greater => {
-                            self.state.indent_size = len as u16;
-                            TokenWithSpan {
-                                token: Token::Indent(Indent {}),
-                                span,
-                            }
+                let start = match start {
+                    Some(start) => start,
+                    None => return Ok(None),
+                };
+                let end = *i;
+                let len = (end - start) as u16;
+                let span = Span { start, end };
+                if let Some(indent_unit) = &self.indent_unit {
+                    let indent = unsafe { self.source.get_unchecked(start..end) };
+                    // `is_multiple_of` requires a newer Rust than this crate supports
+                    #[allow(clippy::manual_is_multiple_of)]
+                    let is_consistent = match indent_unit {
+                        IndentUnit::Spaces(size) => {
+                            indent.bytes().all(|b| b == b' ') && len % (*size).max(1) == 0
                         }
-                        Ordering::Less => {
-                            self.state.indent_size = len as u16;
-                            TokenWithSpan {
-                                token: Token::Dedent(Dedent {}),
-                                span,
-                            }
+                        IndentUnit::Tabs => indent.bytes().all(|b| b == b'\t'),
+                    };
+                    if !is_consistent {
+                        return Err(Error {
+                            kind: ErrorKind::InconsistentIndentation,
+                            span,
+                        });
+                    }
+                }
+                return Ok(Some(match len.cmp(&self.state.indent_size) {
+                    Ordering::Greater => {
+                        self.state.indent_size = len as u16;
+                        TokenWithSpan {
+                            token: Token::Indent(Indent {}),
+                            span,
                         }
-                        Ordering::Equal => TokenWithSpan {
-                            token: Token::Linebreak(Linebreak {}),
+                    }
+                    Ordering::Less => {
+                        self.state.indent_size = len as u16;
+                        TokenWithSpan {
+                            token: Token::Dedent(Dedent {}),
                             span,
-                        },
+                        }
                     }
-                });
+                    Ordering::Equal => TokenWithSpan {
+                        token: Token::Linebreak(Linebreak {}),
+                        span,
+                    },
+                }));
             }
         }
 
-        let offset = self.current_offset();
-        Some(TokenWithSpan {
+        let offset = self.char_offset();
+        Ok(Some(TokenWithSpan {
             token: Token::Eof(Eof {}),
             span: Span {
                 start: offset,
                 end: offset,
             },
-        })
+        }))
     }
 
-    fn scan_block_comment(&mut self) {
+    fn scan_block_comment(&mut self) -> PResult<()> {
         let (start, c) = self.state.chars.next().unwrap();
         debug_assert_eq!(c, '/');
         self.state.chars.next();
@@ -265,6 +476,12 @@ impl<'cmt, 's: 'cmt> Tokenizer<'cmt, 's> {
                 None => {
                     content_end = self.source.len();
                     end = content_end;
+                    if self.strict_block_comment {
+                        return Err(Error {
+                            kind: ErrorKind::UnterminatedBlockComment,
+                            span: Span { start, end },
+                        });
+                    }
                     break;
                 }
             }
@@ -277,6 +494,7 @@ impl<'cmt, 's: 'cmt> Tokenizer<'cmt, 's> {
                 span: Span { start, end },
             }));
         }
+        Ok(())
     }
 
     fn scan_line_comment(&mut self) {
@@ -316,20 +534,18 @@ impl<'cmt, 's: 'cmt> Tokenizer<'cmt, 's> {
 
     pub(crate) fn scan_ident_sequence(&mut self) -> PResult<(Ident<'s>, Span)> {
         let start;
-        let mut end;
         let mut escaped = false;
         match self.state.chars.peek() {
             Some((i, c)) if c.is_ascii_alphabetic() || *c == '_' || !c.is_ascii() => {
                 start = *i;
-                end = start + c.len_utf8();
                 self.state.chars.next();
             }
             Some((i, '-')) => {
                 start = *i;
                 self.state.chars.next();
-                if let Some((i, c)) = self.state.chars.next() {
+                if let Some((_, c)) = self.state.chars.next() {
                     debug_assert!(is_start_of_ident(c));
-                    end = i + c.len_utf8();
+                    escaped = c == '\0';
                 } else {
                     return Err(self.build_eof_error());
                 }
@@ -337,37 +553,45 @@ impl<'cmt, 's: 'cmt> Tokenizer<'cmt, 's> {
             Some((i, '\\')) => {
                 escaped = true;
                 start = *i;
-                end = self.scan_escape(/* backslash_consumed */ false)?;
+                self.scan_escape(/* backslash_consumed */ false)?;
+            }
+            // NUL will be replaced with U+FFFD when handling escapes
+            Some((i, '\0')) => {
+                escaped = true;
+                start = *i;
+                self.state.chars.next();
             }
             _ => unreachable!(),
         }
 
-        while let Some((i, c)) = self.state.chars.peek() {
+        while let Some((_, c)) = self.state.chars.peek() {
             if c.is_ascii_alphanumeric() || *c == '-' || *c == '_' || !c.is_ascii() {
                 self.state.chars.next();
+            } else if c == &'\0' {
+                escaped = true;
+                self.state.chars.next();
             } else if c == &'\\' {
                 escaped = true;
                 self.scan_escape(/* backslash_consumed */ false)?;
             } else {
-                end = *i;
                 break;
             }
         }
+        let end = self.char_offset();
 
         debug_assert!(start < end);
         let raw = unsafe { self.source.get_unchecked(start..end) };
         Ok((Ident { raw, escaped }, Span { start, end }))
     }
 
-    fn scan_escape(&mut self, backslash_consumed: bool) -> PResult<usize> {
+    fn scan_escape(&mut self, backslash_consumed: bool) -> PResult<()> {
         if !backslash_consumed {
             self.state.chars.next(); // consume `\\`
         }
         match self.state.chars.next() {
-            Some((i, c)) if c.is_ascii_hexdigit() => {
+            Some((_, c)) if c.is_ascii_hexdigit() => {
                 let mut count: usize = 1;
-                let mut end = i + 1;
-                while let Some((i, c)) = self.state.chars.peek() {
+                while let Some((_, c)) = self.state.chars.peek() {
                     if c.is_ascii_hexdigit() && count < 6 {
                         count += 1;
                         self.state.chars.next();
@@ -375,34 +599,48 @@ impl<'cmt, 's: 'cmt> Tokenizer<'cmt, 's> {
                         // according to https://www.w3.org/TR/css-syntax-3/#hex-digit,
                         // consume a whitespace
                         if c.is_ascii_whitespace() {
-                            end = i + 1;
                             self.state.chars.next();
-                        } else {
-                            end = *i;
                         }
                         break;
                     }
                 }
-                Ok(end)
+                Ok(())
+            }
+            // line continuation in string
+            Some((_, '\r')) => {
+                self.state.chars.next_if(|(_, c)| *c == '\n');
+                Ok(())
+            }
+            Some(..) => Ok(()),
+            None if self.allow_invalid_escape => {
+                // backslash at the end of file will be decoded literally
+                let end = self.char_offset();
+                self.errors.push(Error {
+                    kind: ErrorKind::InvalidEscape,
+                    span: Span {
+                        start: end - 1,
+                        end,
+                    },
+                });
+                Ok(())
             }
-            Some((i, c)) => Ok(i + c.len_utf8()),
             None => Err(self.build_eof_error()),
         }
     }
 
     fn scan_number(&mut self) -> PResult<(Number<'s>, Span)> {
         let start;
-        let mut end = 0;
 
         let is_start_with_dot;
+        let mut sign_explicit = false;
         match self.state.chars.next() {
             Some((i, c)) if c.is_ascii_digit() => {
                 start = i;
                 is_start_with_dot = false;
-                end = i + 1;
             }
             Some((i, '+' | '-')) => {
                 start = i;
+                sign_explicit = true;
                 is_start_with_dot = matches!(self.state.chars.next(), Some((_, '.')));
             }
             Some((i, '.')) => {
@@ -412,11 +650,10 @@ impl<'cmt, 's: 'cmt> Tokenizer<'cmt, 's> {
             _ => unreachable!(),
         }
 
-        while let Some((i, c)) = self.state.chars.peek() {
+        while let Some((_, c)) = self.state.chars.peek() {
             if c.is_ascii_digit() {
                 self.state.chars.next();
             } else {
-                end = *i;
                 break;
             }
         }
@@ -424,42 +661,43 @@ impl<'cmt, 's: 'cmt> Tokenizer<'cmt, 's> {
             if let Some((_, '.')) = self.state.chars.peek() {
                 // bump '.'
                 self.state.chars.next();
-                while let Some((i, c)) = self.state.chars.peek() {
+                while let Some((_, c)) = self.state.chars.peek() {
                     if c.is_ascii_digit() {
                         self.state.chars.next();
                     } else {
-                        end = *i;
                         break;
                     }
                 }
             }
         }
 
-        match self.peek_two_chars() {
-            Some((_, 'e' | 'E', second))
-                if second == '-' || second == '+' || second.is_ascii_digit() =>
-            {
+        // exponent sign must be followed by a digit, otherwise `e` is start of unit, like `1e-foo`
+        let mut chars = self.state.chars.clone();
+        let has_exponent = match (chars.next(), chars.next(), chars.next()) {
+            (Some((_, 'e' | 'E')), Some((_, c)), ..) if c.is_ascii_digit() => true,
+            (Some((_, 'e' | 'E')), Some((_, '-' | '+')), Some((_, c))) => c.is_ascii_digit(),
+            _ => false,
+        };
+        if has_exponent {
+            self.state.chars.next();
+
+            if let Some((_, '-' | '+')) = self.state.chars.peek() {
                 self.state.chars.next();
+            }
 
-                if let Some((_, '-' | '+')) = self.state.chars.peek() {
+            while let Some((_, c)) = self.state.chars.peek() {
+                if c.is_ascii_digit() {
                     self.state.chars.next();
-                }
-
-                while let Some((i, c)) = self.state.chars.clone().peek() {
-                    if c.is_ascii_digit() {
-                        self.state.chars.next();
-                    } else {
-                        end = *i;
-                        break;
-                    }
+                } else {
+                    break;
                 }
             }
-            _ => {}
         }
 
+        let end = self.char_offset();
         debug_assert!(start < end);
         let raw = unsafe { self.source.get_unchecked(start..end) };
-        Ok((Number { raw }, Span { start, end }))
+        Ok((Number { raw, sign_explicit }, Span { start, end }))
     }
 
     fn scan_dimension_or_percentage(
@@ -521,6 +759,7 @@ impl<'cmt, 's: 'cmt> Tokenizer<'cmt, 's> {
                     escaped = true;
                     self.scan_escape(/* backslash_consumed */ true)?;
                 }
+                Some((_, '\0')) => escaped = true,
                 Some((i, c)) if c == quote => {
                     end = i + 1;
                     break;
@@ -566,7 +805,7 @@ impl<'cmt, 's: 'cmt> Tokenizer<'cmt, 's> {
     }
 
     pub(crate) fn scan_string_template(&mut self, quote: char) -> PResult<(StrTemplate<'s>, Span)> {
-        let start = self.current_offset();
+        let start = self.char_offset();
         let end;
         let mut escaped = false;
         loop {
@@ -584,6 +823,7 @@ impl<'cmt, 's: 'cmt> Tokenizer<'cmt, 's> {
                     escaped = true;
                     self.scan_escape(/* backslash_consumed */ true)?;
                 }
+                Some((_, '\0')) => escaped = true,
                 Some((i, c)) if c == quote => {
                     end = i + c.len_utf8();
                     debug_assert!(start < end);
@@ -640,7 +880,7 @@ impl<'cmt, 's: 'cmt> Tokenizer<'cmt, 's> {
 
     pub(crate) fn scan_url_raw_or_template(&mut self) -> PResult<TokenWithSpan<'s>> {
         self.skip_ws();
-        let start = self.current_offset();
+        let start = self.char_offset();
         let end;
         let mut escaped = false;
         loop {
@@ -649,6 +889,7 @@ impl<'cmt, 's: 'cmt> Tokenizer<'cmt, 's> {
                     escaped = true;
                     self.scan_escape(/* backslash_consumed */ true)?;
                 }
+                Some((_, '\0')) => escaped = true,
                 Some((i, ')')) => {
                     end = i;
                     break;
@@ -674,7 +915,7 @@ impl<'cmt, 's: 'cmt> Tokenizer<'cmt, 's> {
                         }
                         Some((i, c)) => {
                             return Err(Error {
-                                kind: ErrorKind::ExpectUrl,
+                                kind: ErrorKind::InvalidUnquotedUrl,
                                 span: Span {
                                     start: i,
                                     end: i + c.len_utf8(),
@@ -699,7 +940,7 @@ impl<'cmt, 's: 'cmt> Tokenizer<'cmt, 's> {
     }
 
     pub(crate) fn scan_url_template(&mut self) -> PResult<(UrlTemplate<'s>, Span)> {
-        let start = self.current_offset();
+        let start = self.char_offset();
         let mut escaped = false;
         loop {
             match self.state.chars.next() {
@@ -716,6 +957,7 @@ impl<'cmt, 's: 'cmt> Tokenizer<'cmt, 's> {
                     escaped = true;
                     self.scan_escape(/* backslash_consumed */ true)?;
                 }
+                Some((_, '\0')) => escaped = true,
                 Some((end, ')')) => {
                     debug_assert!(start <= end);
 
@@ -759,15 +1001,12 @@ impl<'cmt, 's: 'cmt> Tokenizer<'cmt, 's> {
         let (start, c) = self.state.chars.next().unwrap();
         debug_assert_eq!(c, '#');
 
-        let mut end;
         let mut escaped = false;
         match self.state.chars.next() {
-            Some((i, c)) if c.is_ascii_alphanumeric() || c == '-' || c == '_' || !c.is_ascii() => {
-                end = i + c.len_utf8();
-            }
+            Some((_, c)) if c.is_ascii_alphanumeric() || c == '-' || c == '_' || !c.is_ascii() => {}
             Some((_, '\\')) => {
                 escaped = true;
-                end = self.scan_escape(/* backslash_consumed */ true)?;
+                self.scan_escape(/* backslash_consumed */ true)?;
             }
             Some((i, _)) => {
                 return Err(Error {
@@ -782,17 +1021,17 @@ impl<'cmt, 's: 'cmt> Tokenizer<'cmt, 's> {
                 return Err(self.build_eof_error());
             }
         }
-        while let Some((i, c)) = self.state.chars.peek() {
+        while let Some((_, c)) = self.state.chars.peek() {
             if c.is_ascii_alphanumeric() || *c == '-' || *c == '_' || !c.is_ascii() {
                 self.state.chars.next();
             } else if c == &'\\' {
                 escaped = true;
                 self.scan_escape(/* backslash_consumed */ false)?;
             } else {
-                end = *i;
                 break;
             }
         }
+        let end = self.char_offset();
 
         debug_assert!(end > start + 1);
         let raw = unsafe { self.source.get_unchecked(start + 1..end) };
@@ -987,7 +1226,7 @@ impl<'cmt, 's: 'cmt> Tokenizer<'cmt, 's> {
                         },
                     })
                 }
-                Some((_, '!')) => {
+                Some((_, '!')) if self.syntax == Syntax::Css => {
                     let mut chars = self.state.chars.clone();
                     if {
                         chars.next();
@@ -1255,7 +1494,7 @@ impl<'cmt, 's: 'cmt> Tokenizer<'cmt, 's> {
                 },
             }),
             None => {
-                let offset = self.current_offset();
+                let offset = self.char_offset();
                 Ok(TokenWithSpan {
                     token: Token::Eof(Eof {}),
                     span: Span {
@@ -1295,7 +1534,91 @@ impl<'cmt, 's: 'cmt> Tokenizer<'cmt, 's> {
     }
 }
 
+impl<'cmt, 's: 'cmt> IntoIterator for Tokenizer<'cmt, 's> {
+    type Item = PResult<TokenWithSpan<'s>>;
+    type IntoIter = Tokens<'cmt, 's>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        Tokens {
+            tokenizer: self,
+            finished: false,
+        }
+    }
+}
+
+/// Iterator over tokens of a [`Tokenizer`].
+///
+/// The last item is either the `Eof` token or the first error,
+/// and the iterator will be exhausted after that.
+///
+/// ```rust
+/// use raffia::{token::Token, Syntax, Tokenizer};
+///
+/// let mut comments = vec![];
+/// let tokenizer = Tokenizer::new("a { /* b */ }", Syntax::Css, Some(&mut comments));
+/// let tokens = tokenizer
+///     .into_iter()
+///     .map(|token| token.unwrap().token)
+///     .collect::<Vec<_>>();
+/// assert_eq!(tokens.len(), 4);
+/// assert!(matches!(tokens[3], Token::Eof(..)));
+/// assert_eq!(comments.len(), 1);
+///
+/// let tokenizer = Tokenizer::new("a ` b", Syntax::Css, None);
+/// let mut tokens = tokenizer.into_iter();
+/// assert!(tokens.next().unwrap().is_ok());
+/// assert!(tokens.next().unwrap().is_err());
+/// assert!(tokens.next().is_none());
+/// ```
+pub struct Tokens<'cmt, 's: 'cmt> {
+    tokenizer: Tokenizer<'cmt, 's>,
+    finished: bool,
+}
+
+impl<'cmt, 's: 'cmt> Iterator for Tokens<'cmt, 's> {
+    type Item = PResult<TokenWithSpan<'s>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.finished {
+            return None;
+        }
+        let result = self.tokenizer.bump();
+        self.finished = matches!(
+            result,
+            Ok(TokenWithSpan {
+                token: Token::Eof(..),
+                ..
+            }) | Err(..)
+        );
+        Some(result)
+    }
+}
+
+/// Tokenize a source fragment, such as a single property value, without comments.
+///
+/// This is a shorthand of creating a [`Tokenizer`] and turning it into [`Tokens`].
+///
+/// ```rust
+/// use raffia::{token::Token, tokenize, Syntax};
+///
+/// let tokens = tokenize("10px solid red", Syntax::Css)
+///     .map(|token| token.unwrap().token)
+///     .collect::<Vec<_>>();
+/// assert!(matches!(tokens[0], Token::Dimension(..)));
+/// assert!(matches!(tokens[1], Token::Ident(..)));
+/// assert!(matches!(tokens[2], Token::Ident(..)));
+/// assert!(matches!(tokens[3], Token::Eof(..)));
+///
+/// let tokens = tokenize("$width * 2", Syntax::Scss)
+///     .map(|token| token.unwrap().token)
+///     .collect::<Vec<_>>();
+/// assert!(matches!(tokens[0], Token::DollarVar(..)));
+/// ```
+pub fn tokenize(source: &str, syntax: Syntax) -> Tokens<'_, '_> {
+    Tokenizer::new(source, syntax, None).into_iter()
+}
+
 #[inline]
 fn is_start_of_ident(c: char) -> bool {
-    c.is_ascii_alphabetic() || c == '-' || c == '_' || !c.is_ascii() || c == '\\'
+    c.is_ascii_alphabetic() || c == '-' || c == '_' || !c.is_ascii() || c == '\\' || c == '\0'
 }
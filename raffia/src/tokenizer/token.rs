@@ -29,6 +29,77 @@ pub struct LineComment<'s> {
     pub span: Span,
 }
 
+impl<'s> Comment<'s> {
+    /// Get the content of this comment without leading and trailing ASCII whitespaces.
+    ///
+    /// ```rust
+    /// use raffia::{ast::Stylesheet, ParserBuilder};
+    ///
+    /// let mut comments = vec![];
+    /// let mut parser = ParserBuilder::new("/* a */ /**/ /*  \n  */")
+    ///     .comments(&mut comments)
+    ///     .build();
+    /// parser.parse::<Stylesheet>().unwrap();
+    /// drop(parser);
+    /// let contents: Vec<_> = comments.iter().map(|comment| comment.trimmed_content()).collect();
+    /// assert_eq!(contents, ["a", "", ""]);
+    /// ```
+    pub fn trimmed_content(&self) -> &'s str {
+        match self {
+            Comment::Block(comment) => comment.trimmed_content(),
+            Comment::Line(comment) => comment.trimmed_content(),
+        }
+    }
+
+    /// Check if this is a doc comment which starts with `/**` or `///`.
+    ///
+    /// ```rust
+    /// use raffia::{ast::Stylesheet, ParserBuilder, Syntax};
+    ///
+    /// let mut comments = vec![];
+    /// let mut parser = ParserBuilder::new("/** a */ /* b */ /**/\n/// c\n// d\n//// e\n")
+    ///     .syntax(Syntax::Scss)
+    ///     .comments(&mut comments)
+    ///     .build();
+    /// parser.parse::<Stylesheet>().unwrap();
+    /// drop(parser);
+    /// let docs: Vec<_> = comments.iter().map(|comment| comment.is_doc()).collect();
+    /// assert_eq!(docs, [true, false, false, true, false, false]);
+    /// ```
+    pub fn is_doc(&self) -> bool {
+        match self {
+            Comment::Block(comment) => comment.is_doc(),
+            Comment::Line(comment) => comment.is_doc(),
+        }
+    }
+}
+
+impl<'s> BlockComment<'s> {
+    /// Get the content of this comment without leading and trailing ASCII whitespaces.
+    pub fn trimmed_content(&self) -> &'s str {
+        self.content.trim_matches(|c: char| c.is_ascii_whitespace())
+    }
+
+    /// Check if this comment starts with `/**`.
+    /// Comments like `/**/` and `/*** */` aren't doc comments.
+    pub fn is_doc(&self) -> bool {
+        self.content.starts_with('*') && !self.content.starts_with("**") && self.content != "*"
+    }
+}
+
+impl<'s> LineComment<'s> {
+    /// Get the content of this comment without leading and trailing ASCII whitespaces.
+    pub fn trimmed_content(&self) -> &'s str {
+        self.content.trim_matches(|c: char| c.is_ascii_whitespace())
+    }
+
+    /// Check if this comment starts with `///`.
+    /// Comments like `////` aren't doc comments.
+    pub fn is_doc(&self) -> bool {
+        self.content.starts_with('/') && !self.content.starts_with("//")
+    }
+}
+
 #[derive(Clone, Debug, PartialEq, EnumAsIs)]
 #[cfg_attr(feature = "serialize", derive(Serialize))]
 pub enum Token<'s> {
@@ -92,6 +163,69 @@ pub enum Token<'s> {
     UrlTemplate(UrlTemplate<'s>),
 }
 
+/// Kind of [`Token`] without its payload, which is cheap to copy and compare.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum TokenKind {
+    Eof,
+    Ampersand,
+    Asterisk,
+    AsteriskEqual,
+    At,
+    AtKeyword,
+    AtLBraceVar,
+    BadStr,
+    Bar,
+    BarBar,
+    BarEqual,
+    CaretEqual,
+    Cdc,
+    Cdo,
+    Colon,
+    ColonColon,
+    Comma,
+    Dedent,
+    Dimension,
+    DollarEqual,
+    DollarVar,
+    Dot,
+    DotDotDot,
+    Equal,
+    EqualEqual,
+    Exclamation,
+    ExclamationEqual,
+    GreaterThan,
+    GreaterThanEqual,
+    Hash,
+    HashLBrace,
+    Ident,
+    Indent,
+    LBrace,
+    LBracket,
+    LessThan,
+    LessThanEqual,
+    Linebreak,
+    LParen,
+    Minus,
+    Number,
+    NumberSign,
+    Percent,
+    Percentage,
+    Plus,
+    PlusUnderscore,
+    Question,
+    RBrace,
+    RBracket,
+    RParen,
+    Semicolon,
+    Solidus,
+    Str,
+    StrTemplate,
+    Tilde,
+    TildeEqual,
+    UrlRaw,
+    UrlTemplate,
+}
+
 #[derive(Clone, Debug, Spanned, PartialEq)]
 #[cfg_attr(feature = "serialize", derive(Serialize))]
 #[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
@@ -100,6 +234,87 @@ pub struct TokenWithSpan<'s> {
     pub span: Span,
 }
 
+impl Token<'_> {
+    /// Get the kind of this token.
+    ///
+    /// ```rust
+    /// use raffia::token::{Comma, Token, TokenKind};
+    ///
+    /// let token = Token::Comma(Comma {});
+    /// assert_eq!(token.kind(), TokenKind::Comma);
+    /// assert!(token.is(TokenKind::Comma));
+    /// assert!(!token.is(TokenKind::Semicolon));
+    /// ```
+    pub fn kind(&self) -> TokenKind {
+        match self {
+            Token::Eof(..) => TokenKind::Eof,
+            Token::Ampersand(..) => TokenKind::Ampersand,
+            Token::Asterisk(..) => TokenKind::Asterisk,
+            Token::AsteriskEqual(..) => TokenKind::AsteriskEqual,
+            Token::At(..) => TokenKind::At,
+            Token::AtKeyword(..) => TokenKind::AtKeyword,
+            Token::AtLBraceVar(..) => TokenKind::AtLBraceVar,
+            Token::BadStr(..) => TokenKind::BadStr,
+            Token::Bar(..) => TokenKind::Bar,
+            Token::BarBar(..) => TokenKind::BarBar,
+            Token::BarEqual(..) => TokenKind::BarEqual,
+            Token::CaretEqual(..) => TokenKind::CaretEqual,
+            Token::Cdc(..) => TokenKind::Cdc,
+            Token::Cdo(..) => TokenKind::Cdo,
+            Token::Colon(..) => TokenKind::Colon,
+            Token::ColonColon(..) => TokenKind::ColonColon,
+            Token::Comma(..) => TokenKind::Comma,
+            Token::Dedent(..) => TokenKind::Dedent,
+            Token::Dimension(..) => TokenKind::Dimension,
+            Token::DollarEqual(..) => TokenKind::DollarEqual,
+            Token::DollarVar(..) => TokenKind::DollarVar,
+            Token::Dot(..) => TokenKind::Dot,
+            Token::DotDotDot(..) => TokenKind::DotDotDot,
+            Token::Equal(..) => TokenKind::Equal,
+            Token::EqualEqual(..) => TokenKind::EqualEqual,
+            Token::Exclamation(..) => TokenKind::Exclamation,
+            Token::ExclamationEqual(..) => TokenKind::ExclamationEqual,
+            Token::GreaterThan(..) => TokenKind::GreaterThan,
+            Token::GreaterThanEqual(..) => TokenKind::GreaterThanEqual,
+            Token::Hash(..) => TokenKind::Hash,
+            Token::HashLBrace(..) => TokenKind::HashLBrace,
+            Token::Ident(..) => TokenKind::Ident,
+            Token::Indent(..) => TokenKind::Indent,
+            Token::LBrace(..) => TokenKind::LBrace,
+            Token::LBracket(..) => TokenKind::LBracket,
+            Token::LessThan(..) => TokenKind::LessThan,
+            Token::LessThanEqual(..) => TokenKind::LessThanEqual,
+            Token::Linebreak(..) => TokenKind::Linebreak,
+            Token::LParen(..) => TokenKind::LParen,
+            Token::Minus(..) => TokenKind::Minus,
+            Token::Number(..) => TokenKind::Number,
+            Token::NumberSign(..) => TokenKind::NumberSign,
+            Token::Percent(..) => TokenKind::Percent,
+            Token::Percentage(..) => TokenKind::Percentage,
+            Token::Plus(..) => TokenKind::Plus,
+            Token::PlusUnderscore(..) => TokenKind::PlusUnderscore,
+            Token::Question(..) => TokenKind::Question,
+            Token::RBrace(..) => TokenKind::RBrace,
+            Token::RBracket(..) => TokenKind::RBracket,
+            Token::RParen(..) => TokenKind::RParen,
+            Token::Semicolon(..) => TokenKind::Semicolon,
+            Token::Solidus(..) => TokenKind::Solidus,
+            Token::Str(..) => TokenKind::Str,
+            Token::StrTemplate(..) => TokenKind::StrTemplate,
+            Token::Tilde(..) => TokenKind::Tilde,
+            Token::TildeEqual(..) => TokenKind::TildeEqual,
+            Token::UrlRaw(..) => TokenKind::UrlRaw,
+            Token::UrlTemplate(..) => TokenKind::UrlTemplate,
+        }
+    }
+
+    /// Check if this token is of the given kind.
+    #[inline]
+    pub fn is(&self, kind: TokenKind) -> bool {
+        self.kind() == kind
+    }
+}
+
 #[derive(Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serialize", derive(Serialize))]
 #[cfg_attr(feature = "serialize", serde(tag = "kind", rename_all = "camelCase"))]
@@ -326,6 +541,23 @@ pub struct Minus {}
 #[cfg_attr(feature = "serialize", serde(tag = "kind", rename_all = "camelCase"))]
 pub struct Number<'s> {
     pub raw: &'s str,
+    /// Whether number starts with `+` or `-`.
+    ///
+    /// ```rust
+    /// use raffia::{token::Token, Syntax, Tokenizer};
+    ///
+    /// let mut tokenizer = Tokenizer::new("+1 1 -1px", Syntax::Css, None);
+    /// let mut signs = vec![];
+    /// for token in tokenizer.into_iter().filter_map(Result::ok) {
+    ///     match token.token {
+    ///         Token::Number(number) => signs.push(number.sign_explicit),
+    ///         Token::Dimension(dimension) => signs.push(dimension.value.sign_explicit),
+    ///         _ => {}
+    ///     }
+    /// }
+    /// assert_eq!(signs, [true, false, true]);
+    /// ```
+    pub sign_explicit: bool,
 }
 
 /// U+0023 `#`
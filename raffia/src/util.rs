@@ -7,8 +7,200 @@ pub fn is_css_wide_keyword(s: &str) -> bool {
     s.eq_ignore_ascii_case("initial")
         || s.eq_ignore_ascii_case("inherit")
         || s.eq_ignore_ascii_case("unset")
+        || s.eq_ignore_ascii_case("revert")
+        || s.eq_ignore_ascii_case("revert-layer")
 }
 
+/// Check if it's a named color, `currentColor` or `transparent`, case-insensitively.
+pub fn is_color_keyword(s: &str) -> bool {
+    s.eq_ignore_ascii_case("currentcolor")
+        || s.eq_ignore_ascii_case("transparent")
+        || NAMED_COLORS
+            .binary_search_by(|color| {
+                color
+                    .bytes()
+                    .cmp(s.bytes().map(|byte| byte.to_ascii_lowercase()))
+            })
+            .is_ok()
+}
+
+// https://drafts.csswg.org/css-color-4/#named-colors
+// must be sorted for binary search
+/// Check if value of the given property is a name, such as font family or animation name,
+/// where color-named identifiers aren't colors.
+pub fn is_name_valued_property(s: &str) -> bool {
+    [
+        "font-family",
+        "animation-name",
+        "grid-area",
+        "grid-row",
+        "grid-row-start",
+        "grid-row-end",
+        "grid-column",
+        "grid-column-start",
+        "grid-column-end",
+        "counter-reset",
+        "counter-increment",
+        "counter-set",
+        "list-style-type",
+        "will-change",
+        "transition-property",
+    ]
+    .iter()
+    .any(|property| s.eq_ignore_ascii_case(property))
+}
+
+const NAMED_COLORS: [&str; 148] = [
+    "aliceblue",
+    "antiquewhite",
+    "aqua",
+    "aquamarine",
+    "azure",
+    "beige",
+    "bisque",
+    "black",
+    "blanchedalmond",
+    "blue",
+    "blueviolet",
+    "brown",
+    "burlywood",
+    "cadetblue",
+    "chartreuse",
+    "chocolate",
+    "coral",
+    "cornflowerblue",
+    "cornsilk",
+    "crimson",
+    "cyan",
+    "darkblue",
+    "darkcyan",
+    "darkgoldenrod",
+    "darkgray",
+    "darkgreen",
+    "darkgrey",
+    "darkkhaki",
+    "darkmagenta",
+    "darkolivegreen",
+    "darkorange",
+    "darkorchid",
+    "darkred",
+    "darksalmon",
+    "darkseagreen",
+    "darkslateblue",
+    "darkslategray",
+    "darkslategrey",
+    "darkturquoise",
+    "darkviolet",
+    "deeppink",
+    "deepskyblue",
+    "dimgray",
+    "dimgrey",
+    "dodgerblue",
+    "firebrick",
+    "floralwhite",
+    "forestgreen",
+    "fuchsia",
+    "gainsboro",
+    "ghostwhite",
+    "gold",
+    "goldenrod",
+    "gray",
+    "green",
+    "greenyellow",
+    "grey",
+    "honeydew",
+    "hotpink",
+    "indianred",
+    "indigo",
+    "ivory",
+    "khaki",
+    "lavender",
+    "lavenderblush",
+    "lawngreen",
+    "lemonchiffon",
+    "lightblue",
+    "lightcoral",
+    "lightcyan",
+    "lightgoldenrodyellow",
+    "lightgray",
+    "lightgreen",
+    "lightgrey",
+    "lightpink",
+    "lightsalmon",
+    "lightseagreen",
+    "lightskyblue",
+    "lightslategray",
+    "lightslategrey",
+    "lightsteelblue",
+    "lightyellow",
+    "lime",
+    "limegreen",
+    "linen",
+    "magenta",
+    "maroon",
+    "mediumaquamarine",
+    "mediumblue",
+    "mediumorchid",
+    "mediumpurple",
+    "mediumseagreen",
+    "mediumslateblue",
+    "mediumspringgreen",
+    "mediumturquoise",
+    "mediumvioletred",
+    "midnightblue",
+    "mintcream",
+    "mistyrose",
+    "moccasin",
+    "navajowhite",
+    "navy",
+    "oldlace",
+    "olive",
+    "olivedrab",
+    "orange",
+    "orangered",
+    "orchid",
+    "palegoldenrod",
+    "palegreen",
+    "paleturquoise",
+    "palevioletred",
+    "papayawhip",
+    "peachpuff",
+    "peru",
+    "pink",
+    "plum",
+    "powderblue",
+    "purple",
+    "rebeccapurple",
+    "red",
+    "rosybrown",
+    "royalblue",
+    "saddlebrown",
+    "salmon",
+    "sandybrown",
+    "seagreen",
+    "seashell",
+    "sienna",
+    "silver",
+    "skyblue",
+    "slateblue",
+    "slategray",
+    "slategrey",
+    "snow",
+    "springgreen",
+    "steelblue",
+    "tan",
+    "teal",
+    "thistle",
+    "tomato",
+    "turquoise",
+    "violet",
+    "wheat",
+    "white",
+    "whitesmoke",
+    "yellow",
+    "yellowgreen",
+];
+
 pub trait LastOfNonEmpty<T> {
     /// Return the last element of the given vector.
     ///
@@ -64,11 +256,24 @@ pub fn handle_escape(s: &str) -> CowStr {
                         .get(start..start + count)
                         .and_then(|hexdigits| u32::from_str_radix(hexdigits, 16).ok())
                         .expect("expect unicode value"); // this line should be unreachable
-                    escaped.push(char::from_u32(unicode).unwrap_or(char::REPLACEMENT_CHARACTER));
+                    escaped.push(
+                        char::from_u32(unicode)
+                            .filter(|c| *c != '\0')
+                            .unwrap_or(char::REPLACEMENT_CHARACTER),
+                    );
+                }
+                // line continuation in string, which produces nothing
+                Some((_, '\n' | '\x0C')) => {}
+                Some((_, '\r')) => {
+                    chars.next_if(|(_, c)| *c == '\n');
                 }
+                Some((_, '\0')) => escaped.push(char::REPLACEMENT_CHARACTER),
                 Some((_, c)) => escaped.push(c),
-                None => unreachable!(),
+                // only reachable when invalid escapes are allowed
+                None => escaped.push('\\'),
             }
+        } else if c == '\0' {
+            escaped.push(char::REPLACEMENT_CHARACTER);
         } else {
             escaped.push(c);
         }
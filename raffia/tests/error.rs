@@ -4,7 +4,7 @@ use codespan_reporting::{
     term::{self, termcolor::Buffer},
 };
 use insta::{assert_snapshot, glob, Settings};
-use raffia::{ast::Stylesheet, Parser, Syntax};
+use raffia::{ast::Stylesheet, IndentUnit, ParserBuilder, Syntax};
 use std::fs;
 
 #[test]
@@ -20,7 +20,34 @@ fn error_snapshot() {
             "less" => Syntax::Less,
             _ => unreachable!("unknown file extension"),
         };
-        let mut parser = Parser::new(&code, syntax);
+        let in_dir = |name: &str| {
+            path.components()
+                .any(|component| component.as_os_str() == name)
+        };
+        let mut builder = ParserBuilder::new(&code).syntax(syntax);
+        // fixtures under `strict` directory are parsed with strict validation enabled
+        if in_dir("strict") {
+            builder = builder
+                .strict_media_query_list(true)
+                .strict_declaration_value(true)
+                .strict_css_wide_keyword(true)
+                .strict_keyframes_name(true)
+                .strict_layer_name(true)
+                .strict_not_pseudo_class(true)
+                .strict_property_rule(true)
+                .strict_media_feature_value(true)
+                .strict_block_comment(true)
+                .strict_font_face_descriptor(true);
+        }
+        // fixtures under `indent-spaces` or `indent-tabs` directory require indentation
+        // of indented Sass syntax to be 2 spaces or tabs
+        if in_dir("indent-spaces") {
+            builder = builder.sass_indent_unit(IndentUnit::Spaces(2));
+        }
+        if in_dir("indent-tabs") {
+            builder = builder.sass_indent_unit(IndentUnit::Tabs);
+        }
+        let mut parser = builder.build();
         let error = match parser.parse::<Stylesheet>() {
             Ok(..) => panic!("'{file_name}' should contain unrecoverable syntax error, but actually parsed successfully."),
             Err(error) => {
@@ -4,7 +4,7 @@ use codespan_reporting::{
     term::{self, termcolor::Buffer},
 };
 use insta::{assert_snapshot, glob, Settings};
-use raffia::{ast::Stylesheet, Parser, Syntax};
+use raffia::{ast::Stylesheet, ParserBuilder, Syntax};
 use std::fs;
 
 #[test]
@@ -20,7 +20,21 @@ fn recoverable_errors_snapshot() {
             "less" => Syntax::Less,
             _ => unreachable!("unknown file extension"),
         };
-        let mut parser = Parser::new(&code, syntax);
+        let in_dir = |name: &str| {
+            path.components()
+                .any(|component| component.as_os_str() == name)
+        };
+        let mut builder = ParserBuilder::new(&code).syntax(syntax);
+        if in_dir("deprecated-media") {
+            builder = builder.warn_deprecated_media_features(true);
+        }
+        if in_dir("bad-string") {
+            builder = builder.allow_bad_string(true);
+        }
+        if in_dir("invalid-escape") {
+            builder = builder.allow_invalid_escape(true);
+        }
+        let mut parser = builder.build();
 
         let file = SimpleFile::new(file_name, &code);
         let config = term::Config::default();
@@ -172,3 +172,123 @@ pub fn enum_as_is_derive(input: TokenStream) -> TokenStream {
 
     generated.into()
 }
+
+#[proc_macro_derive(Walk, attributes(ast_node))]
+pub fn walk_derive(input: TokenStream) -> TokenStream {
+    let ast: DeriveInput = syn::parse(input).unwrap();
+    let name = &ast.ident;
+    let generics = &ast.generics;
+    let is_ast_node = ast.attrs.iter().any(|attr| attr.path.is_ident("ast_node"));
+
+    // node types without lifetime still need `'s` for trait
+    let impl_generics = if generics.lifetimes().next().is_some() {
+        quote! { #generics }
+    } else {
+        quote! { <'s> }
+    };
+
+    let (walk_children, walk_mut_children) = match &ast.data {
+        Data::Struct(DataStruct {
+            fields: Fields::Named(fields),
+            ..
+        }) => {
+            let walks = fields
+                .named
+                .iter()
+                .filter_map(|field| field.ident.as_ref())
+                .filter(|ident| *ident != "span")
+                .map(|ident| quote! { self.#ident.walk(f); })
+                .collect::<Vec<_>>();
+            let walks_mut = fields
+                .named
+                .iter()
+                .filter_map(|field| field.ident.as_ref())
+                .filter(|ident| *ident != "span")
+                .map(|ident| quote! { self.#ident.walk_mut(f); });
+            (quote! { #(#walks)* }, quote! { #(#walks_mut)* })
+        }
+        Data::Enum(data_enum) => {
+            let variants = data_enum.variants.iter().map(|variant| {
+                let ident = &variant.ident;
+                match &variant.fields {
+                    Fields::Unnamed(..) => quote! {
+                        Self::#ident(x) => x.walk(f),
+                    },
+                    Fields::Unit => quote! {
+                        Self::#ident => {}
+                    },
+                    _ => unimplemented!("enum variant with named fields is not supported"),
+                }
+            });
+            let variants_mut = data_enum.variants.iter().map(|variant| {
+                let ident = &variant.ident;
+                match &variant.fields {
+                    Fields::Unnamed(..) => quote! {
+                        Self::#ident(x) => x.walk_mut(f),
+                    },
+                    Fields::Unit => quote! {
+                        Self::#ident => {}
+                    },
+                    _ => unimplemented!("enum variant with named fields is not supported"),
+                }
+            });
+            (
+                quote! {
+                    match self {
+                        #(#variants)*
+                    }
+                },
+                quote! {
+                    match self {
+                        #(#variants_mut)*
+                    }
+                },
+            )
+        }
+        _ => unimplemented!(),
+    };
+
+    let body = if is_ast_node {
+        quote! {
+            if f(crate::AstNode::#name(self)) {
+                #walk_children
+            }
+        }
+    } else {
+        walk_children
+    };
+    let body_mut = if is_ast_node {
+        quote! {
+            if f(crate::AstNodeMut::#name(self)) {
+                #walk_mut_children
+            }
+        }
+    } else {
+        walk_mut_children
+    };
+
+    let generated = quote! {
+        impl #impl_generics crate::Walk<'s> for #name #generics {
+            #[allow(unused_variables)]
+            fn walk<'a, F>(&'a self, f: &mut F)
+            where
+                F: FnMut(crate::AstNode<'a, 's>) -> bool,
+                's: 'a,
+            {
+                #body
+            }
+        }
+
+        impl #impl_generics crate::WalkMut<'s> for #name #generics {
+            #[allow(unused_variables)]
+            fn walk_mut<F>(&mut self, f: &mut F)
+            where
+                F: FnMut(crate::AstNodeMut<'_, 's>) -> bool,
+            {
+                #body_mut
+            }
+        }
+    };
+
+    generated.into()
+}